@@ -0,0 +1,199 @@
+use std::cmp::Ordering;
+
+use crate::Big;
+
+/// Fold `values` into a single [Big] using `f`, with the first element as the initial value.
+///
+/// Returns [None] if `values` is empty.
+///
+/// # Example
+/// ```
+/// use bignum_ig::Big;
+///
+/// let values = [Big::from(1), Big::from(2), Big::from(3)];
+/// let sum = bignum_ig::reduce(&values, |acc, value| acc + *value);
+/// assert_eq!(sum, Some(Big::from(6)));
+/// ```
+pub fn reduce(values: &[Big], f: impl Fn(Big, &Big) -> Big) -> Option<Big> {
+    let (first, rest) = values.split_first()?;
+    Some(rest.iter().fold(*first, f))
+}
+
+/// Return the arithmetic mean of `values`, or [None] if `values` is empty.
+fn mean(values: &[Big]) -> Option<Big> {
+    let sum = reduce(values, |acc, value| acc + *value)?;
+    Some(sum / Big::from(values.len() as f64))
+}
+
+/// Return the population variance of `values`, or [None] if `values` is empty.
+///
+/// The squared deviations are computed as [Big], so this does not overflow for huge values.
+/// If any value is NaN, the result is NaN.
+///
+/// # Example
+/// ```
+/// use bignum_ig::Big;
+///
+/// let values = [2, 4, 4, 4, 5, 5, 7, 9].map(Big::from);
+/// assert_eq!(bignum_ig::variance(&values), Some(Big::from(4)));
+/// ```
+pub fn variance(values: &[Big]) -> Option<Big> {
+    let mean = mean(values)?;
+    let mut sum_of_squares = Big::Zero;
+    for value in values {
+        sum_of_squares += (*value - mean).square();
+    }
+    Some(sum_of_squares / Big::from(values.len() as f64))
+}
+
+/// Return the population standard deviation of `values`, or [None] if `values` is empty.
+///
+/// See [variance] for details.
+///
+/// # Example
+/// ```
+/// use bignum_ig::Big;
+///
+/// let values = [2, 4, 4, 4, 5, 5, 7, 9].map(Big::from);
+/// let std_dev = bignum_ig::std_dev(&values).unwrap();
+/// assert!((std_dev - Big::from(2)).abs() < Big::new(1.0, -12));
+/// ```
+pub fn std_dev(values: &[Big]) -> Option<Big> {
+    variance(values).map(|variance| variance.sqrt())
+}
+
+/// Return the median of `values`, or [None] if `values` is empty.
+///
+/// `values` is sorted in-place. For an even number of values, the mean of the two middle values is returned.
+/// If any value is NaN, the result is NaN.
+///
+/// # Example
+/// ```
+/// use bignum_ig::Big;
+///
+/// let mut values = [5, 1, 3, 2].map(Big::from);
+/// assert_eq!(bignum_ig::median(&mut values), Some(Big::from(2.5)));
+/// ```
+pub fn median(values: &mut [Big]) -> Option<Big> {
+    if values.iter().any(Big::is_nan) {
+        return Some(Big::NaN);
+    }
+    // without NaN, only equal Infinities are unordered
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    let middle = values.len() / 2;
+    match values.len() {
+        0 => None,
+        len if len % 2 == 1 => Some(values[middle]),
+        _ => Some((values[middle - 1] + values[middle]) / Big::from(2)),
+    }
+}
+
+/// Return the weighted mean of `(value, weight)` pairs, computing sum(value * weight) / sum(weight).
+///
+/// Returns [None] if `pairs` is empty or the total weight is Zero.
+///
+/// # Example
+/// ```
+/// use bignum_ig::Big;
+///
+/// let pairs = [(Big::from(10), Big::from(1)), (Big::from(20), Big::from(3))];
+/// assert_eq!(bignum_ig::weighted_mean(&pairs), Some(Big::from(17.5)));
+/// ```
+pub fn weighted_mean(pairs: &[(Big, Big)]) -> Option<Big> {
+    let weighted_total: Big = pairs.iter().map(|(value, weight)| *value * *weight).sum();
+    let weight_total: Big = pairs.iter().map(|(_, weight)| weight).sum();
+
+    match weight_total.is_zero() {
+        true => None,
+        false => Some(weighted_total / weight_total),
+    }
+}
+
+/// Sum `values` by recursively adding the sums of both halves, or Zero if `values` is empty.
+///
+/// The rounding error grows with the logarithm of the number of values instead of the number itself,
+/// so this is more accurate than [Sum](std::iter::Sum) for many values of a similar magnitude.
+///
+/// # Example
+/// ```
+/// use bignum_ig::Big;
+///
+/// let values = vec![Big::from(0.1); 1000];
+/// assert_eq!(bignum_ig::sum_pairwise(&values), Big::from(100));
+/// ```
+pub fn sum_pairwise(values: &[Big]) -> Big {
+    match values {
+        [] => Big::Zero,
+        [value] => *value,
+        values => {
+            let (left, right) = values.split_at(values.len() / 2);
+            sum_pairwise(left) + sum_pairwise(right)
+        }
+    }
+}
+
+/// Multiply every value of `values` by `factor` in-place, e.g. to apply a global bonus to an inventory.
+///
+/// The mantissas are scaled directly, so there is no intermediate [Big] per value.
+///
+/// # Example
+/// ```
+/// use bignum_ig::Big;
+///
+/// let mut values = [Big::from(3), Big::new(7.5, 1000)];
+/// bignum_ig::scale_all(&mut values, 2.0);
+/// assert_eq!(values, [Big::from(6), Big::new(1.5, 1001)]);
+/// ```
+pub fn scale_all(values: &mut [Big], factor: f64) {
+    for value in values {
+        match value {
+            Big::Number { m, .. } if (*m * factor).is_finite() => {
+                *m *= factor;
+                value.normalize();
+            }
+            // the mantissa would overflow, or value is Zero, NaN or an Infinity
+            value => *value *= Big::from(factor),
+        }
+    }
+}
+
+/// Return the largest value of `iter`, skipping NaN, or [None] if there is no value other than NaN.
+///
+/// Unlike the functions for slices, this accepts any iterator, e.g. for tracking the highest value seen in a stream.
+///
+/// # Example
+/// ```
+/// use bignum_ig::Big;
+///
+/// let values = [Big::from(3), Big::NaN, Big::from(7), Big::from(5)];
+/// assert_eq!(bignum_ig::max_by_iter(values), Some(Big::from(7)));
+/// ```
+pub fn max_by_iter<I: IntoIterator<Item = Big>>(iter: I) -> Option<Big> {
+    extreme_by_iter(iter, Ordering::Greater)
+}
+
+/// Return the smallest value of `iter`, skipping NaN, or [None] if there is no value other than NaN.
+///
+/// See [max_by_iter] for details.
+///
+/// # Example
+/// ```
+/// use bignum_ig::Big;
+///
+/// let values = [Big::from(3), Big::NaN, Big::from(7), Big::from(5)];
+/// assert_eq!(bignum_ig::min_by_iter(values), Some(Big::from(3)));
+/// ```
+pub fn min_by_iter<I: IntoIterator<Item = Big>>(iter: I) -> Option<Big> {
+    extreme_by_iter(iter, Ordering::Less)
+}
+
+/// Fold `iter` into the value that compares as `wanted` to all others, skipping NaN
+fn extreme_by_iter(iter: impl IntoIterator<Item = Big>, wanted: Ordering) -> Option<Big> {
+    iter.into_iter()
+        .filter(|value| !value.is_nan())
+        .reduce(|extreme, value| match value.partial_cmp(&extreme) {
+            Some(ordering) if ordering == wanted => value,
+            _ => extreme,
+        })
+}
@@ -0,0 +1,72 @@
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::Big;
+
+/// A [Big] that can be shared between threads and updated concurrently, e.g. to accumulate resource gains
+/// from several worker threads.
+///
+/// A [Big] consists of an [prim@f64] and an [prim@i64], which cannot be updated together by a single atomic
+/// instruction, so the value is guarded by a [Mutex]. Every operation only holds the lock for a single addition,
+/// so contention is low. A thread panicking while holding the lock cannot leave a partially updated value
+/// behind, so a poisoned lock is ignored.
+///
+/// # Example
+/// ```
+/// use bignum_ig::{Big, BigAtomic};
+///
+/// let gold = BigAtomic::new(Big::from(100));
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| gold.fetch_add(&Big::from(25)));
+///     }
+/// });
+/// assert_eq!(gold.load(), Big::from(200));
+/// ```
+#[derive(Debug)]
+pub struct BigAtomic {
+    value: Mutex<Big>,
+}
+
+impl BigAtomic {
+    /// Create a new Instance holding `value`
+    pub fn new(value: Big) -> Self {
+        Self {
+            value: Mutex::new(value),
+        }
+    }
+
+    /// Return the current value
+    pub fn load(&self) -> Big {
+        *self.lock()
+    }
+
+    /// Replace the current value with `value`
+    pub fn store(&self, value: Big) {
+        *self.lock() = value;
+    }
+
+    /// Add `rhs` to the current value, returning the previous value
+    pub fn fetch_add(&self, rhs: &Big) -> Big {
+        let mut value = self.lock();
+        let previous = *value;
+        *value += *rhs;
+        previous
+    }
+
+    /// Consume `self`, returning the current value
+    pub fn into_inner(self) -> Big {
+        self.value
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Big> {
+        self.value.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl From<Big> for BigAtomic {
+    fn from(value: Big) -> Self {
+        Self::new(value)
+    }
+}
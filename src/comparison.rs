@@ -1,6 +1,289 @@
 use crate::{Big, InfinityKind, SIG_DIGITS};
 use std::cmp::Ordering;
 
+impl Big {
+    /// Return an approximate [prim@f64] key for fast sorting, which avoids the cost of [Big::partial_cmp()].
+    ///
+    /// The key is [Big::log10()] for positive numbers and the negated [Big::log10()] of the magnitude for
+    /// negative numbers. Infinities map to [f64::INFINITY] and [f64::NEG_INFINITY], Zero maps to `0.0` and
+    /// NaN maps to [f64::NAN].
+    ///
+    /// **Caution:** Keys of the same sign are always ordered correctly. Keys of values with different signs
+    /// are only ordered correctly if both magnitudes are at least 1, because numbers between -1 and 1
+    /// have logarithms of the opposite sign. Values closer than the precision of a [prim@f64] logarithm may tie.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut numbers = vec![Big::from(300), Big::from(-20), Big::new(1.0, 1000)];
+    /// numbers.sort_by(|a, b| a.sort_key().total_cmp(&b.sort_key()));
+    /// assert_eq!(numbers, vec![Big::from(-20), Big::from(300), Big::new(1.0, 1000)]);
+    /// ```
+    pub fn sort_key(&self) -> f64 {
+        match self {
+            Self::Number { m, e } if m.is_sign_negative() => -(m.abs().log10() + *e as f64),
+            Self::Number { m, e } => m.log10() + *e as f64,
+            Self::Infinity(InfinityKind::Positive) => f64::INFINITY,
+            Self::Infinity(InfinityKind::Negative) => f64::NEG_INFINITY,
+            Self::Zero => 0.0,
+            Self::NaN => f64::NAN,
+        }
+    }
+
+    /// Return true if |`self`| is more than `epsilon_oom` orders of magnitude smaller than |`relative_to`|
+    ///
+    /// This is a scale-relative zero test, e.g. for leftovers of subtracting two large numbers.
+    /// Zero is always effectively zero, while NaN and Infinities never are. Any finite number is
+    /// effectively zero relative to an Infinity.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let leftover = Big::new(1.0, 86);
+    /// assert!(leftover.is_effectively_zero(&Big::new(1.0, 100), 12.0));
+    /// assert!(!leftover.is_effectively_zero(&Big::new(1.0, 90), 12.0));
+    /// ```
+    pub fn is_effectively_zero(&self, relative_to: &Big, epsilon_oom: f64) -> bool {
+        match (self, relative_to) {
+            (Self::NaN, _) | (_, Self::NaN) => false,
+            (Self::Zero, _) => true,
+            (Self::Infinity(_), _) => false,
+            (Self::Number { .. }, Self::Infinity(_)) => true,
+            (Self::Number { .. }, Self::Zero) => false,
+            (Self::Number { .. }, Self::Number { .. }) => {
+                relative_to.abs().log10() - self.abs().log10() > epsilon_oom
+            }
+        }
+    }
+
+    /// Return true if `self` is within `tol` of 1, e.g. to skip multiplying by a factor that is almost 1.
+    ///
+    /// Since the reference is 1, the tolerance is relative and absolute at the same time.
+    /// NaN and Infinities are never approximately 1.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert!(Big::from(1.0000001).is_approx_one(1e-6));
+    /// assert!(!Big::from(1.001).is_approx_one(1e-6));
+    /// ```
+    pub fn is_approx_one(&self, tol: f64) -> bool {
+        match self {
+            Self::Number { .. } => (*self - Self::from(1)).abs() <= Self::from(tol),
+            _ => false,
+        }
+    }
+
+    /// Return true if |`self`| is at most `tol`, e.g. to skip adding a term that is almost 0.
+    ///
+    /// `tol` is relative to 1, which is the scale of [Big::is_approx_one()]. To compare against the scale of
+    /// another value, see [Big::is_effectively_zero()]. NaN and Infinities are never approximately 0.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert!(Big::new(1.0, -9).is_approx_zero(1e-6));
+    /// assert!(!Big::new(1.0, -3).is_approx_zero(1e-6));
+    /// ```
+    pub fn is_approx_zero(&self, tol: f64) -> bool {
+        match self {
+            Self::Zero => tol >= 0.0,
+            Self::Number { .. } => self.abs() <= Self::from(tol),
+            _ => false,
+        }
+    }
+
+    /// Return true if |`self`| is at most `tol`, like [Big::is_approx_zero()] with a [Big] tolerance,
+    /// e.g. for tolerances below the range of [prim@f64].
+    ///
+    /// NaN and Infinities are never within any tolerance, and nothing is within a NaN or negative tolerance.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let residue = Big::new(1.0, -400);
+    /// assert!(residue.is_zero_within(&Big::new(1.0, -300)));
+    /// assert!(!residue.is_zero_within(&Big::Zero));
+    /// ```
+    pub fn is_zero_within(&self, tol: &Big) -> bool {
+        match self {
+            Self::Zero | Self::Number { .. } => self.abs() <= *tol,
+            Self::NaN | Self::Infinity(_) => false,
+        }
+    }
+
+    /// Compare only the exponents of two numbers, ignoring their mantissas.
+    ///
+    /// This is a fast, coarse comparison for tier-based logic: two values in the same decade compare
+    /// [Ordering::Equal], e.g. 2 and 9. Since the mantissa is ignored, so is the sign of two numbers.
+    /// If either value is NaN, an Infinity or Zero, the result is the same as [Big::partial_cmp()].
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Big::from(2).exponent_cmp(&Big::from(9)), Some(Ordering::Equal));
+    /// assert_eq!(Big::from(99).exponent_cmp(&Big::from(100)), Some(Ordering::Less));
+    /// ```
+    pub fn exponent_cmp(&self, other: &Big) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Number { e, .. }, Self::Number { e: other_e, .. }) => Some(e.cmp(other_e)),
+            _ => self.partial_cmp(other),
+        }
+    }
+
+    /// Compare `self` and `other` with a total order, like [f64::total_cmp()].
+    ///
+    /// The order is -inf < negative numbers < Zero < positive numbers < +inf < NaN.
+    /// Unlike [Big::partial_cmp()], equal Infinities and two NaN compare [Ordering::Equal].
+    /// To use this order where [Ord] is required, e.g. for [slice::sort()], see [TotalOrd].
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::{Big, POS_INFINITY};
+    ///
+    /// let mut numbers = vec![Big::NaN, Big::from(2), POS_INFINITY, Big::from(-1)];
+    /// numbers.sort_by(Big::total_cmp);
+    /// assert_eq!(numbers[0], Big::from(-1));
+    /// assert!(numbers[3].is_nan());
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        let rank = |value: &Big| match value {
+            Self::Infinity(InfinityKind::Negative) => 0,
+            Self::Number { .. } | Self::Zero => 1,
+            Self::Infinity(InfinityKind::Positive) => 2,
+            Self::NaN => 3,
+        };
+
+        rank(self)
+            .cmp(&rank(other))
+            .then_with(|| self.partial_cmp(other).unwrap_or(Ordering::Equal))
+    }
+
+    /// Return the smaller of `self` and `other`.
+    ///
+    /// Unlike [f64::min()], NaN is propagated: if either value is NaN, the result is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(3).min(Big::from(5)), Big::from(3));
+    /// assert!(Big::from(3).min(Big::NaN).is_nan());
+    /// ```
+    pub fn min(self, other: Big) -> Big {
+        match (&self, &other) {
+            (Self::NaN, _) | (_, Self::NaN) => Self::NaN,
+            _ if other < self => other,
+            _ => self,
+        }
+    }
+
+    /// Return the larger of `self` and `other`.
+    ///
+    /// Unlike [f64::max()], NaN is propagated: if either value is NaN, the result is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(3).max(Big::from(5)), Big::from(5));
+    /// assert!(Big::from(3).max(Big::NaN).is_nan());
+    /// ```
+    pub fn max(self, other: Big) -> Big {
+        match (&self, &other) {
+            (Self::NaN, _) | (_, Self::NaN) => Self::NaN,
+            _ if other > self => other,
+            _ => self,
+        }
+    }
+
+    /// Restrict `self` to the range `min`..=`max`.
+    ///
+    /// If `self` or either bound is NaN, the result is NaN. To ignore NaN bounds instead, see [Big::clamp_checked()].
+    ///
+    /// # Panics
+    /// In debug builds, if `min` > `max`.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(150).clamp(Big::Zero, Big::from(100)), Big::from(100));
+    /// assert_eq!(Big::from(-5).clamp(Big::Zero, Big::from(100)), Big::Zero);
+    /// ```
+    pub fn clamp(self, min: Big, max: Big) -> Big {
+        // NaN bounds and equal Infinities do not compare, so this does not assert min <= max
+        debug_assert!(
+            min.partial_cmp(&max) != Some(Ordering::Greater),
+            "min must not be greater than max"
+        );
+        self.max(min).min(max)
+    }
+
+    /// Clamp `self` to the range `min`..=`max`, returning the clamped value and whether clamping occurred.
+    ///
+    /// If `self` is NaN, `(NaN, false)` is returned. A NaN bound is ignored, because nothing compares to it.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let (value, clamped) = Big::from(150).clamp_checked(&Big::from(0), &Big::from(100));
+    /// assert_eq!(value, Big::from(100));
+    /// assert!(clamped);
+    /// ```
+    pub fn clamp_checked(&self, min: &Big, max: &Big) -> (Big, bool) {
+        if self < min {
+            (*min, true)
+        } else if self > max {
+            (*max, true)
+        } else {
+            (*self, false)
+        }
+    }
+}
+
+/// A wrapper around [Big] that implements [Ord] with [Big::total_cmp()], e.g. for sorting or as a key in a
+/// [BTreeMap](std::collections::BTreeMap). [Big] itself keeps the semantics of [PartialOrd].
+///
+/// # Example
+/// ```
+/// use bignum_ig::{Big, TotalOrd};
+///
+/// let mut leaderboard = vec![TotalOrd(Big::from(300)), TotalOrd(Big::NaN), TotalOrd(Big::from(20))];
+/// leaderboard.sort();
+/// assert_eq!(leaderboard[0], TotalOrd(Big::from(20)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TotalOrd(pub Big);
+
+impl Ord for TotalOrd {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl PartialOrd for TotalOrd {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for TotalOrd {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TotalOrd {}
+
 impl PartialOrd for Big {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match (self, other) {
@@ -46,9 +329,17 @@ impl PartialOrd for Big {
                     m: other_m,
                     e: other_e,
                 },
-            ) => match other_e - e {
-                delta if delta >= SIG_DIGITS => Some(Ordering::Greater),
-                delta if delta <= -SIG_DIGITS => Some(Ordering::Less),
+            ) => match other_e.saturating_sub(*e) {
+                // the magnitude of other is much larger, so its sign decides
+                delta if delta >= SIG_DIGITS => match other_m.is_sign_positive() {
+                    true => Some(Ordering::Less),
+                    false => Some(Ordering::Greater),
+                },
+                // the magnitude of self is much larger, so its sign decides
+                delta if delta <= -SIG_DIGITS => match m.is_sign_positive() {
+                    true => Some(Ordering::Greater),
+                    false => Some(Ordering::Less),
+                },
                 delta => {
                     let m_normalized = other_m * 10_f64.powi(delta as i32);
                     if m_normalized == *m {
@@ -1,6 +1,253 @@
 use std::{error::Error, fmt::Display, str::FromStr};
 
-use crate::Big;
+use crate::{
+    fmt_simple::{NAMES, SUFFIXES},
+    Big, InfinityKind, NEG_INFINITY, POS_INFINITY,
+};
+
+impl Big {
+    /// Create a new Instance from a mantissa that is already normalized, skipping normalization.
+    ///
+    /// Returns [ParseError::Mantissa] if `m` is not within ±1.0..10.0. This is the safe counterpart
+    /// to [Big::new_unnormalized()].
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from_parts_checked(4.2, 1), Ok(Big::from(42)));
+    /// assert!(Big::from_parts_checked(42.0, 0).is_err());
+    /// ```
+    pub fn from_parts_checked(m: f64, e: i64) -> Result<Big, ParseError> {
+        let number = Big::new_unnormalized(m, e);
+        match number.is_normalized() {
+            true => Ok(number),
+            false => Err(ParseError::Mantissa(m.to_string())),
+        }
+    }
+
+    /// Create a new Instance from the ratio `numer / denom`.
+    ///
+    /// A `denom` of 0 results in an Infinity with the sign of `numer`, or NaN if `numer` is 0 as well.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from_ratio(3, 4), Big::from(0.75));
+    /// assert!(Big::from_ratio(1, 0).is_pos_inf());
+    /// assert!(Big::from_ratio(0, 0).is_nan());
+    /// ```
+    pub fn from_ratio(numer: i64, denom: i64) -> Big {
+        match (numer.signum(), denom) {
+            (0, 0) => Big::NaN,
+            (1, 0) => POS_INFINITY,
+            (_, 0) => NEG_INFINITY,
+            _ => Big::from(numer) / Big::from(denom),
+        }
+    }
+
+    /// Convert `self` into an [prim@i64], truncating towards zero.
+    ///
+    /// Values out of range saturate at [i64::MAX] and [i64::MIN], including the Infinities. NaN becomes 0.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(-42.9).to_i64_saturating(), -42);
+    /// assert_eq!(Big::new(1.0, 100).to_i64_saturating(), i64::MAX);
+    /// ```
+    pub fn to_i64_saturating(&self) -> i64 {
+        match self {
+            Big::Number { e, .. } if *e < 0 => 0,
+            // float to int casts truncate and saturate, and 10^400 is infinite anyway
+            Big::Number { m, e } => (m * 10f64.powi((*e).min(400) as i32)) as i64,
+            Big::Infinity(InfinityKind::Positive) => i64::MAX,
+            Big::Infinity(InfinityKind::Negative) => i64::MIN,
+            Big::Zero | Big::NaN => 0,
+        }
+    }
+
+    /// Convert `self` into an [prim@f64], e.g. for a graphics API.
+    ///
+    /// Numbers that are too large for an [prim@f64] become [f64::INFINITY] or [f64::NEG_INFINITY],
+    /// and numbers that are too small become subnormal or 0.0. To detect overflows, use [f64::try_from()] instead.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(1234.5).to_f64(), 1234.5);
+    /// assert_eq!(Big::new(1.0, 400).to_f64(), f64::INFINITY);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        match self {
+            // powers of 10 up to 10^22 are exact, so there is only a single rounding step
+            Big::Number { m, e } if (0..=22).contains(e) => m * 10f64.powi(*e as i32),
+            Big::Number { m, e } if (-22..0).contains(e) => m / 10f64.powi(-e as i32),
+            Big::Number { m, e } if *e > 400 => f64::INFINITY.copysign(*m),
+            Big::Number { m, e } if *e < -400 => 0f64.copysign(*m),
+            // parsing rounds correctly, including overflows and subnormal numbers
+            Big::Number { m, e } => format!("{m}e{e}").parse().unwrap_or(f64::NAN),
+            Big::Infinity(InfinityKind::Positive) => f64::INFINITY,
+            Big::Infinity(InfinityKind::Negative) => f64::NEG_INFINITY,
+            Big::Zero => 0.0,
+            Big::NaN => f64::NAN,
+        }
+    }
+
+    /// Convert `self` into a JSON value that can safely be consumed by JavaScript.
+    ///
+    /// Numbers in the range of a [prim@f64] become JSON numbers, while larger or smaller numbers and
+    /// NaN/Infinities become JSON strings in the format of [Display], which can be parsed again.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(42).to_json_value(), serde_json::json!(42.0));
+    /// assert_eq!(Big::new(1.5, 400).to_json_value(), serde_json::json!("1.5e400"));
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let number = match self {
+            Big::Zero => Some(0.0),
+            Big::Number { m, e }
+                if (f64::MIN_10_EXP as i64..=f64::MAX_10_EXP as i64).contains(e) =>
+            {
+                Some(m * 10f64.powi(*e as i32))
+            }
+            _ => None,
+        };
+
+        match number.and_then(serde_json::Number::from_f64) {
+            Some(number) => serde_json::Value::Number(number),
+            None => serde_json::Value::String(self.to_string()),
+        }
+    }
+}
+
+impl Big {
+    /// Parse lenient human input, trying these formats in order:
+    ///
+    /// - Named numbers: `1.5 million`, `2 billion`
+    /// - Short scale suffixes: `1.5K`, `3.2Qa`
+    /// - Grouped decimals: `2,000`, `-1,234,567.89`
+    /// - Anything [Big::from_str()] accepts: `3e4`, `1234.5`
+    ///
+    /// Names and suffixes are case-insensitive. If nothing matches, the Error of [Big::from_str()] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::parse_human("1.5 million"), Ok(Big::from(1_500_000)));
+    /// assert_eq!(Big::parse_human("1.5K"), Ok(Big::from(1500)));
+    /// assert_eq!(Big::parse_human("2,000"), Ok(Big::from(2000)));
+    /// assert_eq!(Big::parse_human("3e4"), Ok(Big::from(30000)));
+    /// ```
+    pub fn parse_human(s: &str) -> Result<Big, ParseError> {
+        let s = s.trim();
+        let lowercase = s.to_lowercase();
+
+        if let Some((number, name)) = lowercase.rsplit_once(char::is_whitespace) {
+            if let Some(index) = NAMES.iter().position(|n| *n == name) {
+                if let Ok(number) = parse_grouped(number.trim()) {
+                    return Ok(number.scale_by_pow10(3 * (index as i64 + 1)));
+                }
+            }
+        }
+
+        for (index, suffix) in SUFFIXES.iter().enumerate() {
+            if let Some(number) = lowercase.strip_suffix(&suffix.to_lowercase()) {
+                if let Ok(number) = parse_grouped(number.trim()) {
+                    return Ok(number.scale_by_pow10(3 * (index as i64 + 1)));
+                }
+            }
+        }
+
+        parse_grouped(s)
+    }
+
+    /// Parse a JSON number token, like `123456789012345678901234567890` or `-1.5e400`, without going through
+    /// an [prim@f64] first.
+    ///
+    /// The exponent is built by counting digits, so integers with more digits than an [prim@f64] can hold keep
+    /// their magnitude and leading significant digits. Returns [ParseError::Mantissa] if the digits before
+    /// the exponent are invalid and [ParseError::Exponent] if the exponent is invalid.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let number = Big::from_json_number_str("123456789012345678901234567890").unwrap();
+    /// assert_eq!(number, Big::new(1.2345678901234568, 29));
+    /// ```
+    pub fn from_json_number_str(s: &str) -> Result<Big, ParseError> {
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, s),
+        };
+        let (digits, exponent) = match unsigned.split_once(['e', 'E']) {
+            Some((digits, exponent)) => match exponent.parse::<i64>() {
+                Ok(exponent) => (digits, exponent),
+                Err(_) => return Err(ParseError::Exponent(exponent.to_string())),
+            },
+            None => (unsigned, 0),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) if !fraction.is_empty() => (integer, fraction),
+            // a decimal point must be followed by digits
+            Some(_) => ("", ""),
+            None => (digits, ""),
+        };
+        let is_valid = !integer.is_empty()
+            && integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit());
+        if !is_valid {
+            return Err(ParseError::Mantissa(digits.to_string()));
+        }
+
+        let all_digits = format!("{integer}{fraction}");
+        let Some(first) = all_digits.find(|c| c != '0') else {
+            return Ok(Big::Zero);
+        };
+        // more digits than this do not change the mantissa of an f64
+        let significant = &all_digits[first..all_digits.len().min(first + 17)];
+        let m = format!("{}.{}", &significant[..1], &significant[1..])
+            .parse::<f64>()
+            .map_err(|_| ParseError::Mantissa(digits.to_string()))?;
+
+        let mut number = Big::new_unnormalized(if negative { -m } else { m }, 0);
+        number
+            .set_exponent_saturating(exponent as i128 + integer.len() as i128 - 1 - first as i128);
+        // rounding to 17 digits might carry over to 10.0
+        number.normalize();
+        Ok(number)
+    }
+}
+
+/// Parse a decimal with comma separated groups of three digits, like `1,234,567.89`, falling back to [Big::from_str()]
+fn parse_grouped(s: &str) -> Result<Big, ParseError> {
+    let unsigned = s.strip_prefix('-').unwrap_or(s);
+    let integer = unsigned.split('.').next().unwrap_or_default();
+    let mut groups = integer.split(',');
+    let first_is_valid = groups
+        .next()
+        .is_some_and(|group| (1..=3).contains(&group.len()));
+    let is_grouped = integer.contains(',')
+        && first_is_valid
+        && groups.all(|group| group.len() == 3)
+        && integer.chars().all(|c| c.is_ascii_digit() || c == ',');
+
+    match is_grouped {
+        true => s.replace(',', "").parse(),
+        false => s.parse(),
+    }
+}
 
 impl From<f64> for Big {
     fn from(value: f64) -> Self {
@@ -26,10 +273,177 @@ impl From<i32> for Big {
     }
 }
 
+impl From<i16> for Big {
+    fn from(value: i16) -> Self {
+        Big::new(value as f64, 0)
+    }
+}
+
+impl From<i8> for Big {
+    fn from(value: i8) -> Self {
+        Big::new(value as f64, 0)
+    }
+}
+
+impl From<isize> for Big {
+    fn from(value: isize) -> Self {
+        Big::new(value as f64, 0)
+    }
+}
+
+impl From<u64> for Big {
+    fn from(value: u64) -> Self {
+        Big::new(value as f64, 0)
+    }
+}
+
+impl From<u32> for Big {
+    fn from(value: u32) -> Self {
+        Big::new(value as f64, 0)
+    }
+}
+
+impl From<u16> for Big {
+    fn from(value: u16) -> Self {
+        Big::new(value as f64, 0)
+    }
+}
+
+impl From<u8> for Big {
+    fn from(value: u8) -> Self {
+        Big::new(value as f64, 0)
+    }
+}
+
+impl From<usize> for Big {
+    fn from(value: usize) -> Self {
+        Big::new(value as f64, 0)
+    }
+}
+
+/// Values with more significant digits than an [prim@f64] can hold are rounded, like `value as f64`.
+impl From<i128> for Big {
+    fn from(value: i128) -> Self {
+        Big::new(value as f64, 0)
+    }
+}
+
+/// Values with more significant digits than an [prim@f64] can hold are rounded, like `value as f64`.
+impl From<u128> for Big {
+    fn from(value: u128) -> Self {
+        Big::new(value as f64, 0)
+    }
+}
+
+/// The Error returned when converting a [Big] into an [prim@f64] would overflow, see [Big::to_f64()]
+#[derive(Debug, PartialEq)]
+pub struct TryFromBigError;
+
+impl Display for TryFromBigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Number is out of the range of f64")
+    }
+}
+
+impl Error for TryFromBigError {}
+
+/// NaN and Infinities are converted into their [prim@f64] counterparts, and numbers that are too small
+/// become subnormal or 0.0 like [Big::to_f64()]. Numbers that are too large result in an Error.
+impl TryFrom<Big> for f64 {
+    type Error = TryFromBigError;
+
+    fn try_from(value: Big) -> Result<Self, Self::Error> {
+        match (value, value.to_f64()) {
+            (Big::Number { .. }, number) if number.is_infinite() => Err(TryFromBigError),
+            (_, number) => Ok(number),
+        }
+    }
+}
+
+/// A C compatible representation of a [Big], for passing it over an FFI boundary. See [Big::to_repr()].
+///
+/// `mantissa` and `exponent` are only meaningful if `tag` is [BigRepr::TAG_NUMBER], otherwise they are 0.
+#[repr(C)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BigRepr {
+    /// Which variant of [Big] this is, one of the `TAG_` constants
+    pub tag: u8,
+    /// The mantissa of a [Big::Number]
+    pub mantissa: f64,
+    /// The exponent of a [Big::Number]
+    pub exponent: i64,
+}
+
+impl BigRepr {
+    /// The tag of a [Big::Number]
+    pub const TAG_NUMBER: u8 = 0;
+    /// The tag of [Big::Zero]
+    pub const TAG_ZERO: u8 = 1;
+    /// The tag of [Big::NaN]
+    pub const TAG_NAN: u8 = 2;
+    /// The tag of positive Infinity
+    pub const TAG_POS_INFINITY: u8 = 3;
+    /// The tag of negative Infinity
+    pub const TAG_NEG_INFINITY: u8 = 4;
+}
+
+impl Big {
+    /// Convert `self` into its C compatible representation
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::{Big, BigRepr};
+    ///
+    /// let repr = Big::new(1.5, 300).to_repr();
+    /// assert_eq!(repr.tag, BigRepr::TAG_NUMBER);
+    /// assert_eq!((repr.mantissa, repr.exponent), (1.5, 300));
+    /// ```
+    pub fn to_repr(&self) -> BigRepr {
+        let (tag, mantissa, exponent) = match self {
+            Big::Number { m, e } => (BigRepr::TAG_NUMBER, *m, *e),
+            Big::Zero => (BigRepr::TAG_ZERO, 0.0, 0),
+            Big::NaN => (BigRepr::TAG_NAN, 0.0, 0),
+            Big::Infinity(InfinityKind::Positive) => (BigRepr::TAG_POS_INFINITY, 0.0, 0),
+            Big::Infinity(InfinityKind::Negative) => (BigRepr::TAG_NEG_INFINITY, 0.0, 0),
+        };
+
+        BigRepr {
+            tag,
+            mantissa,
+            exponent,
+        }
+    }
+
+    /// Create a new Instance from its C compatible representation.
+    ///
+    /// The mantissa of a number is normalized, since it may come from anywhere. Unknown tags result in NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let number = Big::new(-1.5, 300);
+    /// assert_eq!(Big::from_repr(number.to_repr()), number);
+    /// ```
+    pub fn from_repr(repr: BigRepr) -> Big {
+        match repr.tag {
+            BigRepr::TAG_NUMBER => Big::new(repr.mantissa, repr.exponent),
+            BigRepr::TAG_ZERO => Big::Zero,
+            BigRepr::TAG_POS_INFINITY => POS_INFINITY,
+            BigRepr::TAG_NEG_INFINITY => NEG_INFINITY,
+            _ => Big::NaN,
+        }
+    }
+}
+
+/// The Error returned when a [Big] could not be parsed or constructed
 #[derive(Debug, PartialEq)]
 pub enum ParseError {
+    /// The input could not be split into a mantissa and an exponent
     Parts,
+    /// The mantissa is invalid
     Mantissa(String),
+    /// The exponent is invalid
     Exponent(String),
 }
 
@@ -56,11 +470,16 @@ impl FromStr for Big {
             _ => {}
         }
 
+        let has_exponent = s.contains(['e', 'E']);
         if let Ok(number) = s.parse::<f64>() {
-            return Ok(Big::from(number));
+            // exponents out of the range of an f64 over- or underflow, they are parsed below
+            if number.is_normal() || !has_exponent {
+                return Ok(Big::from(number));
+            }
         }
 
-        let mut iter = s.split("e");
+        // the exponent may have an explicit sign, like 1.5E+400, which i64::parse accepts
+        let mut iter = s.split(['e', 'E']);
         match (iter.next(), iter.next(), iter.next()) {
             (Some(m), Some(e), None) => match (m.parse(), e.parse()) {
                 (Ok(m), Ok(e)) => Ok(Big::new(m, e)),
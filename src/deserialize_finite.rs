@@ -0,0 +1,42 @@
+//! Deserialize a [Big] that is always finite, for loading untrusted data like save files.
+//!
+//! NaN is replaced with [Big::Zero], +inf with [Big::MAX] and -inf with [Big::MIN].
+//! Use it on a field with `#[serde(with = "bignum_ig::deserialize_finite")]`. Serializing is unchanged.
+//!
+//! # Example
+//! ```
+//! use bignum_ig::Big;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Save {
+//!     #[serde(with = "bignum_ig::deserialize_finite")]
+//!     gold: Big,
+//! }
+//!
+//! let save: Save = serde_json::from_str(r#"{ "gold": "NaN" }"#).unwrap();
+//! assert_eq!(save.gold, Big::Zero);
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Big, InfinityKind};
+
+/// Deserialize a [Big], replacing NaN and Infinities with finite values
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Big, D::Error> {
+    let mut value = Big::deserialize(deserializer)?;
+    // the derived implementation does not normalize, so a mantissa could still be NaN or infinite
+    value.normalize();
+
+    Ok(match value {
+        Big::NaN => Big::Zero,
+        Big::Infinity(InfinityKind::Positive) => Big::MAX,
+        Big::Infinity(InfinityKind::Negative) => Big::MIN,
+        value => value,
+    })
+}
+
+/// Serialize a [Big] like its [Serialize] implementation
+pub fn serialize<S: Serializer>(value: &Big, serializer: S) -> Result<S::Ok, S::Error> {
+    value.serialize(serializer)
+}
@@ -1,8 +1,264 @@
-use crate::{Big, SIG_DIGITS};
+use crate::{Big, InfinityKind, SIG_DIGITS};
 
+pub mod notation;
 #[cfg(test)]
 mod tests;
 
+/// Seconds in a year of 365 days
+const YEAR: u64 = 31_536_000;
+
+/// Short scale suffixes, where the suffix at index `i` stands for 10<sup>3 * (i + 1)</sup>
+pub(crate) const SUFFIXES: [&str; 11] =
+    ["K", "M", "B", "T", "Qa", "Qi", "Sx", "Sp", "Oc", "No", "Dc"];
+
+/// Short scale names, where the name at index `i` stands for 10<sup>3 * (i + 1)</sup>
+pub(crate) const NAMES: [&str; 21] = [
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+    "duodecillion",
+    "tredecillion",
+    "quattuordecillion",
+    "quindecillion",
+    "sexdecillion",
+    "septendecillion",
+    "octodecillion",
+    "novemdecillion",
+    "vigintillion",
+];
+
+/// Binary prefixes, where the prefix at index `i` stands for 1024<sup>i + 1</sup>
+pub(crate) const BINARY_PREFIXES: [&str; 8] = ["Ki", "Mi", "Gi", "Ti", "Pi", "Ei", "Zi", "Yi"];
+
+/// Format `values` like [Big::to_exponential] with the same width, so they line up in a column of monospace text.
+///
+/// Negative numbers start with `-`, everything else with a space. Mantissas are right-aligned, so the
+/// decimal points line up, and exponents are left-aligned after the `e`. NaN and Infinities, including
+/// their sign, are right-aligned.
+///
+/// # Example
+/// ```
+/// use bignum_ig::Big;
+///
+/// let values = [Big::from(1234), Big::from(-5), Big::new(1.5, 100)];
+/// assert_eq!(
+///     bignum_ig::format_column(&values, 2),
+///     vec![" 1.23e3  ", "-5.00e0  ", " 1.50e100"]
+/// );
+/// ```
+pub fn format_column(values: &[Big], places: usize) -> Vec<String> {
+    let components: Vec<DisplayComponents> = values
+        .iter()
+        .map(|value| value.components(places))
+        .collect();
+    let exponents: Vec<String> = components
+        .iter()
+        .map(|components| components.exponent.to_string())
+        .collect();
+
+    let mantissa_width = components
+        .iter()
+        .filter(|components| components.is_special.is_none())
+        .map(|components| components.mantissa.len())
+        .max()
+        .unwrap_or(0);
+    let exponent_width = exponents.iter().map(String::len).max().unwrap_or(0);
+
+    let bodies: Vec<String> = components
+        .iter()
+        .zip(&exponents)
+        .map(|(components, exponent)| match components.is_special {
+            Some(special) if components.sign == '-' => format!("-{special}"),
+            Some(special) => special.to_string(),
+            None => format!(
+                "{:>mantissa_width$}e{exponent:<exponent_width$}",
+                components.mantissa
+            ),
+        })
+        .collect();
+    let width = bodies.iter().map(String::len).max().unwrap_or(0);
+
+    components
+        .iter()
+        .zip(bodies)
+        .map(|(components, body)| match components.sign {
+            '-' if components.is_special.is_none() => format!("-{body:>width$}"),
+            _ => format!(" {body:>width$}"),
+        })
+        .collect()
+}
+
+/// Split a number into a mantissa within ±1..1000 and its power of 1000, so 1234.5 becomes (1.2345, 1).
+///
+/// If the mantissa would be rounded to 1000 with `places`, the next power of 1000 is used instead.
+fn split_thousands(m: f64, e: i64, places: usize) -> (f64, i64) {
+    let mantissa = m * 10f64.powi(e.rem_euclid(3) as i32);
+    let tier = e.div_euclid(3);
+    // e.g. 999.999 would be rounded to 1000.00 instead of 1.00K
+    match format!("{mantissa:.places$}")
+        .parse::<f64>()
+        .is_ok_and(|rounded| rounded.abs() >= 1000.0)
+    {
+        true => (mantissa / 1000.0, tier + 1),
+        false => (mantissa, tier),
+    }
+}
+
+/// Return the letters of the `tier`-th power of 1000, starting from `aa` for 1000 and counting up
+/// like spreadsheet columns: `aa`..`az`, `ba`..`zz`, `aaa`..`zzz` and so on.
+fn tier_letters(tier: i64) -> String {
+    let mut index = (tier - 1) as u128;
+    let mut len = 2;
+    while index >= 26u128.pow(len) {
+        index -= 26u128.pow(len);
+        len += 1;
+    }
+
+    let mut letters = vec![b'a'; len as usize];
+    for letter in letters.iter_mut().rev() {
+        *letter += (index % 26) as u8;
+        index /= 26;
+    }
+    String::from_utf8(letters).expect("letters are ASCII")
+}
+
+/// Format `n` with `sep` between every group of 3 digits, so 1234567 becomes `1,234,567`
+fn group_digits(n: i128, sep: char) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut grouped = String::with_capacity(digits.len() * 4 / 3 + 1);
+    if n < 0 {
+        grouped.push('-');
+    }
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(sep);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Round a mantissa to `places`, carrying into the exponent if it would be rounded to 10, so 9.999e5 becomes ("1.00", 6).
+///
+/// The exponent is widened because the carry can exceed [i64::MAX]; it is only displayed.
+pub(crate) fn round_mantissa(m: f64, e: i64, places: usize) -> (String, i128) {
+    let mantissa = format!("{m:.places$}");
+    match mantissa
+        .parse::<f64>()
+        .is_ok_and(|rounded| rounded.abs() >= 10.0)
+    {
+        true => (format!("{:.places$}", m / 10.0), e as i128 + 1),
+        false => (mantissa, e as i128),
+    }
+}
+
+/// The parts of a formatted [Big], for laying them out independently. See [Big::components()].
+#[derive(Debug, PartialEq, Clone)]
+pub struct DisplayComponents {
+    /// `'-'` for negative numbers and -inf, `'+'` for everything else
+    pub sign: char,
+    /// The absolute mantissa, formatted with a fixed number of places. Empty for NaN and Infinities.
+    pub mantissa: String,
+    /// The exponent, 0 for Zero, NaN and Infinities
+    pub exponent: i64,
+    /// `Some("NaN")` or `Some("inf")` for the special values, [None] otherwise
+    pub is_special: Option<&'static str>,
+}
+
+/// How to round the mantissa in [Big::to_exponential_rounded()]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RoundMode {
+    /// Round to the nearest value, ties to the even digit. This is what [Big::to_exponential] does.
+    HalfEven,
+    /// Round to the nearest value, ties away from zero
+    HalfUp,
+    /// Round towards zero
+    Down,
+    /// Round away from zero
+    Up,
+}
+
+/// A unit of time for [Big::to_duration()], ordered from the shortest to the longest unit
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum TimeUnit {
+    /// A second, shown as `s`
+    Second,
+    /// 60 seconds, shown as `m`
+    Minute,
+    /// 60 minutes, shown as `h`
+    Hour,
+    /// 24 hours, shown as `d`
+    Day,
+    /// 365 days, shown as `y`
+    Year,
+}
+
+impl TimeUnit {
+    /// All units from the longest to the shortest
+    const DESCENDING: [TimeUnit; 5] = [
+        TimeUnit::Year,
+        TimeUnit::Day,
+        TimeUnit::Hour,
+        TimeUnit::Minute,
+        TimeUnit::Second,
+    ];
+
+    /// Return the length of the unit in seconds
+    pub fn seconds(self) -> u64 {
+        match self {
+            Self::Second => 1,
+            Self::Minute => 60,
+            Self::Hour => 3_600,
+            Self::Day => 86_400,
+            Self::Year => YEAR,
+        }
+    }
+
+    /// Return the short symbol of the unit, like `h` for hours
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Second => "s",
+            Self::Minute => "m",
+            Self::Hour => "h",
+            Self::Day => "d",
+            Self::Year => "y",
+        }
+    }
+
+    /// Return the plural name of the unit, like `hours`
+    fn plural(self) -> &'static str {
+        match self {
+            Self::Second => "seconds",
+            Self::Minute => "minutes",
+            Self::Hour => "hours",
+            Self::Day => "days",
+            Self::Year => "years",
+        }
+    }
+}
+
+/// The sign of a delta formatted with [Big::to_delta_string()], e.g. for coloring gains and losses
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DeltaSign {
+    /// A gain, including +inf
+    Positive,
+    /// A loss, including -inf
+    Negative,
+    /// No change
+    Zero,
+    /// NaN, which is neither a gain nor a loss
+    NaN,
+}
+
 impl Big {
     /// Formats the number in the format of number.places
     ///
@@ -17,26 +273,97 @@ impl Big {
     /// ```
     pub fn to_fixed(&self, places: usize) -> String {
         match self {
-            Self::Zero => format!("0.{}", "0".repeat(places)),
+            Self::Zero => format!("{:.places$}", 0.0),
             Self::Number { m, e } => {
                 if *e >= SIG_DIGITS {
-                    let result = m.to_string().replace(".", "");
-                    let remaining_places = (*e as usize) - result.len() + 1;
+                    // the sign is not a digit, so it must not count towards the length
+                    let sign = if m.is_sign_negative() { "-" } else { "" };
+                    let mut digits = m.abs().to_string().replace(".", "");
+                    if digits.len() > *e as usize + 1 {
+                        // round to the integer digits, which might carry over to one more digit
+                        digits = format!("{:.1$}", m.abs(), *e as usize).replace(".", "");
+                    }
+                    let remaining_places = (*e as usize + 1).saturating_sub(digits.len());
                     return format!(
-                        "{result}{0}.{1}",
+                        "{sign}{digits}{0}.{1}",
                         "0".repeat(remaining_places),
                         "0".repeat(places)
                     );
                 }
-                let m = m * 10f64.powi(*e as i32);
+                // anything smaller than this rounds to zero, which also keeps the exponent in i32 range
+                let e = (*e).max(-(places as i64) - 2);
+                // dividing by an exact power of 10 avoids the rounding error of a negative powi
+                let m = if e < 0 {
+                    m / 10f64.powi(-e as i32)
+                } else {
+                    m * 10f64.powi(e as i32)
+                };
                 format!("{m:.0$}", places)
             }
             slf => slf.to_string(),
         }
     }
 
+    /// Formats the number like [Big::to_fixed] with up to `max_places`, but trims trailing zeros
+    /// and a trailing decimal point.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(1.5).to_fixed_trimmed(4), "1.5");
+    /// assert_eq!(Big::from(2).to_fixed_trimmed(4), "2");
+    /// ```
+    pub fn to_fixed_trimmed(&self, max_places: usize) -> String {
+        match self {
+            Self::Zero | Self::Number { .. } => {
+                let fixed = self.to_fixed(max_places);
+                let trimmed = match fixed.contains('.') {
+                    true => fixed.trim_end_matches('0').trim_end_matches('.'),
+                    false => &fixed,
+                };
+                // tiny negative numbers round to zero, which has no sign
+                match trimmed {
+                    "-0" => "0".to_string(),
+                    trimmed => trimmed.to_string(),
+                }
+            }
+            slf => slf.to_string(),
+        }
+    }
+
+    /// Formats the number like [Big::to_fixed], but pads the integer part with zeros to at least `int_width` digits,
+    /// like an odometer. The sign is placed before the padding.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(42).to_fixed_min_width(2, 4), "0042.00");
+    /// assert_eq!(Big::from(-42).to_fixed_min_width(0, 4), "-0042");
+    /// ```
+    pub fn to_fixed_min_width(&self, places: usize, int_width: usize) -> String {
+        match self {
+            Self::Zero | Self::Number { .. } => {
+                let fixed = self.to_fixed(places);
+                let (sign, digits) = match fixed.strip_prefix('-') {
+                    Some(digits) => ("-", digits),
+                    None => ("", fixed.as_str()),
+                };
+                let int_len = digits.find('.').unwrap_or(digits.len());
+                format!(
+                    "{sign}{}{digits}",
+                    "0".repeat(int_width.saturating_sub(int_len))
+                )
+            }
+            slf => slf.to_string(),
+        }
+    }
+
     /// Formats the number in the format of mantissa.places**e**exponent
     ///
+    /// If rounding carries the mantissa over to 10, the exponent is increased instead.
+    ///
     /// # Example
     ///
     /// ```
@@ -46,11 +373,525 @@ impl Big {
     /// ```
     pub fn to_exponential(&self, places: usize) -> String {
         match self {
-            Self::Zero => format!("0.{}", "0".repeat(places)),
+            Self::Zero => format!("{:.places$}", 0.0),
+            Self::Number { m, e } => {
+                let (mantissa, e) = round_mantissa(*m, *e, places);
+                format!("{mantissa}e{e}")
+            }
+            slf => slf.to_string(),
+        }
+    }
+
+    /// Formats the number like [Big::to_exponential], but rounds the mantissa to `places` with `mode`.
+    ///
+    /// The mantissa is rounded as the decimal it represents, so 1.15 rounded down to 2 places stays 1.15 even though
+    /// the closest [prim@f64] is slightly smaller. If rounding carries the mantissa over to 10, the exponent is
+    /// increased instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::{Big, RoundMode};
+    ///
+    /// assert_eq!(Big::from(1.25).to_exponential_rounded(1, RoundMode::HalfEven), "1.2e0");
+    /// assert_eq!(Big::from(1.25).to_exponential_rounded(1, RoundMode::HalfUp), "1.3e0");
+    /// ```
+    pub fn to_exponential_rounded(&self, places: usize, mode: RoundMode) -> String {
+        match self {
+            Self::Number { m, e } => {
+                // an f64 has about SIG_DIGITS significant digits, the rest is padded with zeros
+                let padding = "0".repeat(places.saturating_sub(SIG_DIGITS as usize - 1));
+                let places = places.min(SIG_DIGITS as usize - 1);
+                let scale = 10f64.powi(places as i32);
+                // e.g. 1.15 * 100 is 114.99999999999999, so round to the digits of the decimal value first
+                let scaled: f64 = format!("{:.1$e}", m * scale, SIG_DIGITS as usize - 1)
+                    .parse()
+                    .expect("a formatted f64 can be parsed");
+                let rounded = match mode {
+                    RoundMode::HalfEven => scaled.round_ties_even(),
+                    RoundMode::HalfUp => scaled.round(),
+                    RoundMode::Down => scaled.trunc(),
+                    RoundMode::Up => scaled.abs().ceil().copysign(scaled),
+                } / scale;
+
+                let (mantissa, e) = round_mantissa(rounded, *e, places);
+                format!("{mantissa}{padding}e{e}")
+            }
+            slf => slf.to_exponential(places),
+        }
+    }
+
+    /// Formats the number with a short scale suffix, like `1.23K`, `4.56M` or `7.89B`.
+    ///
+    /// Numbers below 1000 are formatted like [Big::to_fixed], and numbers of 1000 decillion (`Dc`) or more
+    /// like [Big::to_exponential]. Zero, NaN and Infinities are formatted like [Display](std::fmt::Display).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(1234.5678).to_suffix(2), "1.23K");
+    /// assert_eq!(Big::from(-4.56e6).to_suffix(2), "-4.56M");
+    /// assert_eq!(Big::from(999).to_suffix(2), "999.00");
+    /// ```
+    pub fn to_suffix(&self, places: usize) -> String {
+        match self {
+            Self::Number { e, .. } if *e < 0 => self.to_fixed(places),
             Self::Number { m, e } => {
-                format!("{m:.0$}e{e}", places)
+                let (mantissa, tier) = split_thousands(*m, *e, places);
+                match tier {
+                    0 => format!("{mantissa:.places$}"),
+                    tier if tier as usize <= SUFFIXES.len() => {
+                        format!("{mantissa:.places$}{}", SUFFIXES[tier as usize - 1])
+                    }
+                    _ => self.to_exponential(places),
+                }
             }
             slf => slf.to_string(),
         }
     }
+
+    /// Formats the number as a byte count with a binary prefix, like `1.5Ki` for 1536 or `2.0Mi` for 2097152.
+    ///
+    /// Every prefix stands for a power of 1024. Numbers below 1024 are formatted like [Big::to_fixed], and numbers
+    /// of 1024 yobi (`Yi`) or more like [Big::to_exponential]. Zero, NaN and Infinities are formatted like
+    /// [Display](std::fmt::Display).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(1536).to_binary_prefix(1), "1.5Ki");
+    /// assert_eq!(Big::from(512).to_binary_prefix(1), "512.0");
+    /// ```
+    pub fn to_binary_prefix(&self, places: usize) -> String {
+        // 1024^9 is about 1.24e27, so larger exponents are always beyond the table
+        const MAX_EXPONENT: i64 = 28;
+
+        match self {
+            Self::Number { e, .. } if *e < 0 => self.to_fixed(places),
+            Self::Number { m, e } if *e < MAX_EXPONENT => {
+                let mut value = m * 10f64.powi(*e as i32);
+                let mut tier = 0;
+                // e.g. 1023.99 would be rounded to 1024.00 instead of 1.00Ki
+                while format!("{value:.places$}")
+                    .parse::<f64>()
+                    .is_ok_and(|rounded| rounded.abs() >= 1024.0)
+                {
+                    value /= 1024.0;
+                    tier += 1;
+                }
+                match tier {
+                    0 => format!("{value:.places$}"),
+                    tier if tier <= BINARY_PREFIXES.len() => {
+                        format!("{value:.places$}{}", BINARY_PREFIXES[tier - 1])
+                    }
+                    _ => self.to_exponential(places),
+                }
+            }
+            Self::Number { .. } => self.to_exponential(places),
+            slf => slf.to_string(),
+        }
+    }
+
+    /// Formats the number in letter notation, like `1.23aa` for 1230 or `4.56ab` for 4.56 million.
+    ///
+    /// Every power of 1000 gets its own letters, starting with `aa` for 1000 and counting up like spreadsheet
+    /// columns: `aa`, `ab`, .. `az`, `ba`, .. `zz`, `aaa` and so on, so there are no short scale suffixes like K.
+    /// Numbers below 1000 are formatted like [Big::to_fixed], and Zero, NaN and Infinities are formatted like
+    /// [Display](std::fmt::Display).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(1234).to_letter_notation(2), "1.23aa");
+    /// assert_eq!(Big::from(4.56e6).to_letter_notation(2), "4.56ab");
+    /// assert_eq!(Big::new(1.0, 81).to_letter_notation(2), "1.00ba");
+    /// ```
+    pub fn to_letter_notation(&self, places: usize) -> String {
+        match self {
+            Self::Number { e, .. } if *e < 0 => self.to_fixed(places),
+            Self::Number { m, e } => match split_thousands(*m, *e, places) {
+                (mantissa, 0) => format!("{mantissa:.places$}"),
+                (mantissa, tier) => format!("{mantissa:.places$}{}", tier_letters(tier)),
+            },
+            slf => slf.to_string(),
+        }
+    }
+
+    /// Formats the number with a short scale name, like `1.23 million` or `4.50 billion`.
+    ///
+    /// Numbers below a million are formatted like [Big::to_fixed], and numbers of 1000 vigintillion
+    /// or more like [Big::to_exponential]. Zero, NaN and Infinities are formatted like [Display](std::fmt::Display).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(1.234e6).to_scientific_name(2), "1.23 million");
+    /// assert_eq!(Big::from(999999).to_scientific_name(0), "999999");
+    /// ```
+    pub fn to_scientific_name(&self, places: usize) -> String {
+        self.to_scientific_name_with(places, Big::to_exponential)
+    }
+
+    /// Formats the number like [Big::to_scientific_name], but formats numbers beyond the table of names
+    /// with `fallback`, which is called with `self` and `places`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let number = Big::new(1.0, 100);
+    /// assert_eq!(number.to_scientific_name_with(2, |_, _| "a lot".to_string()), "a lot");
+    /// ```
+    pub fn to_scientific_name_with(
+        &self,
+        places: usize,
+        fallback: impl FnOnce(&Big, usize) -> String,
+    ) -> String {
+        match self {
+            Self::Number { m, e } if *e < 6 => {
+                let fixed = self.to_fixed(places);
+                // e.g. 999999.999 would be rounded to 1000000.00 instead of 1.00 million
+                match fixed
+                    .parse::<f64>()
+                    .is_ok_and(|rounded| rounded.abs() >= 1e6)
+                {
+                    true => format!("{:.places$} {}", m.signum(), NAMES[1]),
+                    false => fixed,
+                }
+            }
+            Self::Number { m, e } => {
+                let (mantissa, tier) = split_thousands(*m, *e, places);
+                match tier {
+                    tier if tier as usize <= NAMES.len() => {
+                        format!("{mantissa:.places$} {}", NAMES[tier as usize - 1])
+                    }
+                    _ => fallback(self, places),
+                }
+            }
+            slf => slf.to_string(),
+        }
+    }
+
+    /// Formats the number as a change, like [Big::to_exponential] but with a sign for both gains and losses,
+    /// like `+1.23e5` or `-4.50e3`. Zero is formatted as `0` without a sign.
+    ///
+    /// To color the delta, see [Big::delta_sign()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(123000).to_delta_string(2), "+1.23e5");
+    /// assert_eq!(Big::from(-4500).to_delta_string(1), "-4.5e3");
+    /// assert_eq!(Big::Zero.to_delta_string(2), "0");
+    /// ```
+    pub fn to_delta_string(&self, places: usize) -> String {
+        match self {
+            Self::Number { m, .. } if m.is_sign_positive() => {
+                format!("+{}", self.to_exponential(places))
+            }
+            Self::Number { .. } => self.to_exponential(places),
+            slf => slf.to_string(),
+        }
+    }
+
+    /// Return the [DeltaSign] of `self`, which tells if a delta is a gain, a loss or no change.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::{Big, DeltaSign};
+    ///
+    /// assert_eq!(Big::from(-4500).delta_sign(), DeltaSign::Negative);
+    /// assert_eq!(Big::Zero.delta_sign(), DeltaSign::Zero);
+    /// ```
+    pub fn delta_sign(&self) -> DeltaSign {
+        match self {
+            Self::Number { m, .. } if m.is_sign_positive() => DeltaSign::Positive,
+            Self::Number { .. } => DeltaSign::Negative,
+            Self::Infinity(InfinityKind::Positive) => DeltaSign::Positive,
+            Self::Infinity(InfinityKind::Negative) => DeltaSign::Negative,
+            Self::Zero => DeltaSign::Zero,
+            Self::NaN => DeltaSign::NaN,
+        }
+    }
+
+    /// Formats the number like [Big::to_exponential], but groups the digits of the exponent with `sep`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::new(1.2345, 1234567).to_exponential_grouped_exp(2, ','), "1.23e1,234,567");
+    /// ```
+    pub fn to_exponential_grouped_exp(&self, places: usize, sep: char) -> String {
+        match self {
+            Self::Number { m, e } => {
+                let (mantissa, e) = round_mantissa(*m, *e, places);
+                format!("{mantissa}e{}", group_digits(e, sep))
+            }
+            slf => slf.to_exponential(places),
+        }
+    }
+
+    /// Formats the number in engineering notation, where the exponent is a multiple of 3, like `12.3e3`.
+    ///
+    /// The displayed mantissa is within ±1..1000. Zero, NaN and Infinities are formatted like [Big::to_exponential].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(1.23e4).to_engineering(1), "12.3e3");
+    /// assert_eq!(Big::from(5e-4).to_engineering(0), "500e-6");
+    /// ```
+    pub fn to_engineering(&self, places: usize) -> String {
+        match self {
+            Self::Number { m, e } => {
+                let (mantissa, tier) = split_thousands(*m, *e, places);
+                // tier * 3 can exceed i64 if the mantissa was rounded up to the next tier
+                format!("{mantissa:.places$}e{}", tier as i128 * 3)
+            }
+            slf => slf.to_exponential(places),
+        }
+    }
+
+    /// Formats the number for logging, with the derived [Debug] output followed by a readable form,
+    /// like `Number { m: 1.23, e: 45 } (≈1.23e45)`.
+    ///
+    /// Numbers are rounded like [Big::to_exponential] with 2 places, while Zero, NaN and Infinities
+    /// are formatted like [Display](std::fmt::Display).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::new(1.23, 45).debug_str(), "Number { m: 1.23, e: 45 } (≈1.23e45)");
+    /// assert_eq!(Big::Zero.debug_str(), "Zero (0)");
+    /// ```
+    pub fn debug_str(&self) -> String {
+        match self {
+            Self::Number { .. } => format!("{self:?} (≈{})", self.to_exponential(2)),
+            slf => format!("{slf:?} ({slf})"),
+        }
+    }
+
+    /// Formats the number with `notation`, which can be chosen at runtime, e.g. from a settings menu.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::{notation::SuffixNotation, Big};
+    ///
+    /// assert_eq!(Big::from(1234).format_with(&SuffixNotation, 2), "1.23K");
+    /// ```
+    pub fn format_with(&self, notation: &dyn notation::Notation, places: usize) -> String {
+        notation.format(self, places)
+    }
+
+    /// Formats the number like [Big::to_exponential], but reserves a leading space for the sign
+    /// of non-negative numbers, so positive and negative numbers line up in a column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(1234.5678).to_exponential_aligned(2), " 1.23e3");
+    /// assert_eq!(Big::from(-1234.5678).to_exponential_aligned(2), "-1.23e3");
+    /// ```
+    pub fn to_exponential_aligned(&self, places: usize) -> String {
+        match self {
+            Self::Zero => format!(" {}", self.to_exponential(places)),
+            Self::Number { m, .. } if m.is_sign_positive() => {
+                format!(" {}", self.to_exponential(places))
+            }
+            slf => slf.to_exponential(places),
+        }
+    }
+
+    /// Formats the number like [Big::to_exponential], but if the exponent has more than `max_exp_digits` digits,
+    /// the exponent itself is formatted in exponential notation with `max_exp_digits - 1` places.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::new(1.2345, 1234567).to_exponential_capped_exp(2, 2), "1.23e1.2e6");
+    /// assert_eq!(Big::new(1.2345, 12).to_exponential_capped_exp(2, 2), "1.23e12");
+    /// ```
+    pub fn to_exponential_capped_exp(&self, places: usize, max_exp_digits: usize) -> String {
+        match self {
+            Self::Number { m, e } => {
+                let (mantissa, e) = round_mantissa(*m, *e, places);
+                let digits = e.unsigned_abs().checked_ilog10().map_or(1, |log| log + 1);
+                if digits as usize <= max_exp_digits {
+                    return format!("{mantissa}e{e}");
+                }
+
+                let exponent = Big::from(e as f64).to_exponential(max_exp_digits.saturating_sub(1));
+                format!("{mantissa}e{exponent}")
+            }
+            slf => slf.to_exponential(places),
+        }
+    }
+
+    /// Formats the number in nested exponential notation, which drops the mantissa for very large exponents.
+    ///
+    /// - If the exponent has at most 6 digits, the number is formatted like [Big::to_exponential], like `1.23e456`.
+    /// - Otherwise the number is formatted as **e**log10, like `e1234567.30` for 10<sup>1234567.3</sup>.
+    /// - If the logarithm reaches 10<sup>15</sup>, where an [prim@f64] can no longer show its fractional part,
+    ///   the logarithm itself is formatted the same way, like `ee18.96` for 10<sup>10<sup>18.96</sup></sup>.
+    ///   This is as deep as it gets, because the exponent of a [Big] is an [prim@i64].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::new(2.0, 1234567).to_nested(2), "e1234567.30");
+    /// assert_eq!(Big::new(1.0, i64::MAX).to_nested(2), "ee18.96");
+    /// ```
+    pub fn to_nested(&self, places: usize) -> String {
+        const NESTED_EXPONENT: u64 = 1_000_000;
+        const NESTED_LOG: f64 = 1e15;
+
+        match self {
+            Self::Number { m, e } if e.unsigned_abs() >= NESTED_EXPONENT => {
+                let sign = if m.is_sign_negative() { "-" } else { "" };
+                let log = m.abs().log10() + *e as f64;
+                let exponent = if log.abs() < NESTED_LOG {
+                    format!("{log:.places$}")
+                } else {
+                    let log_sign = if log.is_sign_negative() { "-" } else { "" };
+                    format!("{log_sign}e{:.places$}", log.abs().log10())
+                };
+                format!("{sign}e{exponent}")
+            }
+            slf => slf.to_exponential(places),
+        }
+    }
+
+    /// Formats the number as a duration in seconds, in the format of `1y 3d 4h 12m 5s`.
+    /// Units that are zero are omitted and the duration is rounded to whole seconds.
+    ///
+    /// Durations of a million years or more are formatted with [Big::to_exponential] instead, like `2.3e10 years`.
+    /// To choose which units are shown, see [Big::to_duration()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(274320).to_duration_string(), "3d 4h 12m");
+    /// assert_eq!(Big::new(7.2, 17).to_duration_string(), "2.3e10 years");
+    /// ```
+    pub fn to_duration_string(&self) -> String {
+        self.to_duration(TimeUnit::Year, TimeUnit::Second)
+    }
+
+    /// Formats the number as a duration in seconds like [Big::to_duration_string], but only shows the units
+    /// from `largest` down to `smallest`, like `3h 12m`.
+    ///
+    /// The duration is rounded to whole `smallest` units, and anything beyond `largest` is counted in `largest`
+    /// units, like `50h` if days are not shown. Durations of a million `largest` units or more are formatted with
+    /// [Big::to_exponential] instead, like `2.3e10 years`. If `largest` is shorter than `smallest`, they are swapped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::{Big, TimeUnit};
+    ///
+    /// assert_eq!(Big::from(11545).to_duration(TimeUnit::Hour, TimeUnit::Minute), "3h 12m");
+    /// assert_eq!(Big::from(180000).to_duration(TimeUnit::Hour, TimeUnit::Hour), "50h");
+    /// ```
+    pub fn to_duration(&self, largest: TimeUnit, smallest: TimeUnit) -> String {
+        let (largest, smallest) = (largest.max(smallest), largest.min(smallest));
+        let zero = format!("0{}", smallest.symbol());
+
+        match self {
+            Self::Zero => zero,
+            Self::Number { m, .. } if m.is_sign_negative() => {
+                match self.abs().to_duration(largest, smallest) {
+                    duration if duration == zero => duration,
+                    duration => format!("-{duration}"),
+                }
+            }
+            Self::Number { .. } => {
+                let count = *self / Big::from(largest.seconds());
+                if count >= Big::new(1.0, 6) {
+                    return format!("{} {}", count.to_exponential(1), largest.plural());
+                }
+
+                // less than a million `largest` units, so this is exact
+                let mut remaining = (self.to_f64() / smallest.seconds() as f64).round() as u64;
+                let parts: Vec<String> = TimeUnit::DESCENDING
+                    .iter()
+                    .filter(|unit| (smallest..=largest).contains(unit))
+                    .filter_map(|unit| {
+                        // every unit is a multiple of all shorter units
+                        let size = unit.seconds() / smallest.seconds();
+                        let count = remaining / size;
+                        remaining %= size;
+                        (count > 0).then(|| format!("{count}{}", unit.symbol()))
+                    })
+                    .collect();
+
+                match parts.is_empty() {
+                    true => zero,
+                    false => parts.join(" "),
+                }
+            }
+            slf => slf.to_string(),
+        }
+    }
+
+    /// Splits the number into its formatted parts, with the mantissa formatted like [Big::to_exponential].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let components = Big::from(-1234.5678).components(2);
+    /// assert_eq!(components.sign, '-');
+    /// assert_eq!(components.mantissa, "1.23");
+    /// assert_eq!(components.exponent, 3);
+    /// assert_eq!(components.is_special, None);
+    /// ```
+    pub fn components(&self, places: usize) -> DisplayComponents {
+        let (sign, mantissa, exponent, is_special) = match self {
+            Self::Zero => ('+', format!("{:.places$}", 0.0), 0, None),
+            Self::Number { m, e } => {
+                let sign = if m.is_sign_negative() { '-' } else { '+' };
+                let (mantissa, e) = round_mantissa(m.abs(), *e, places);
+                // only a carry at i64::MAX doesn't fit
+                (sign, mantissa, e.try_into().unwrap_or(i64::MAX), None)
+            }
+            Self::NaN => ('+', String::new(), 0, Some("NaN")),
+            Self::Infinity(InfinityKind::Positive) => ('+', String::new(), 0, Some("inf")),
+            Self::Infinity(InfinityKind::Negative) => ('-', String::new(), 0, Some("inf")),
+        };
+
+        DisplayComponents {
+            sign,
+            mantissa,
+            exponent,
+            is_special,
+        }
+    }
 }
@@ -0,0 +1,62 @@
+//! Pluggable notations for formatting a [Big], see [Big::format_with()].
+
+use crate::Big;
+
+/// A way of formatting a [Big]
+///
+/// Implementers only need to format [Big::Number]s, Zero, NaN and Infinities are formatted by [Notation::format()].
+pub trait Notation {
+    /// Format `value`, which is always a [Big::Number], with `places` decimal places
+    fn format_number(&self, value: &Big, places: usize) -> String;
+
+    /// Format `value` with `places` decimal places.
+    ///
+    /// By default, numbers are formatted with [Notation::format_number()],
+    /// while Zero, NaN and Infinities are formatted like [Display](std::fmt::Display).
+    fn format(&self, value: &Big, places: usize) -> String {
+        match value {
+            Big::Number { .. } => self.format_number(value, places),
+            special => special.to_string(),
+        }
+    }
+}
+
+/// Scientific notation like `1.23e45`, see [Big::to_exponential()]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct ScientificNotation;
+
+impl Notation for ScientificNotation {
+    fn format_number(&self, value: &Big, places: usize) -> String {
+        value.to_exponential(places)
+    }
+}
+
+/// Short scale suffixes like `1.23K`, see [Big::to_suffix()]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct SuffixNotation;
+
+impl Notation for SuffixNotation {
+    fn format_number(&self, value: &Big, places: usize) -> String {
+        value.to_suffix(places)
+    }
+}
+
+/// Letter notation like `1.23aa`, see [Big::to_letter_notation()]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct LetterNotation;
+
+impl Notation for LetterNotation {
+    fn format_number(&self, value: &Big, places: usize) -> String {
+        value.to_letter_notation(places)
+    }
+}
+
+/// Engineering notation like `12.3e45`, where the exponent is a multiple of 3
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct EngineeringNotation;
+
+impl Notation for EngineeringNotation {
+    fn format_number(&self, value: &Big, places: usize) -> String {
+        value.to_engineering(places)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Big;
+use crate::{Big, DeltaSign, DisplayComponents, RoundMode};
 
 fn b<T>(value: T) -> Big
 where
@@ -16,12 +16,547 @@ fn to_fixed() {
         format!("1234{}.{}", "0".repeat(497), "0".repeat(200))
     );
     assert_eq!(Big::NaN.to_fixed(2), "NaN");
+    assert_eq!(b(0).to_fixed(0), "0");
+}
+
+#[test]
+fn to_fixed_large_negative() {
+    assert_eq!(
+        Big::new(-1.234, 20).to_fixed(2),
+        "-123400000000000000000.00"
+    );
+    assert_eq!(Big::new(-9.5, 15).to_fixed(1), "-9500000000000000.0");
+    // 17 significant digits are more than the integer digits of 1e15
+    assert_eq!(
+        Big::new(-1.2345678901234567, 15).to_fixed(1),
+        "-1234567890123457.0"
+    );
+    assert_eq!(
+        Big::new(9.999999999999998, 15).to_fixed(1),
+        "9999999999999998.0"
+    );
+}
+
+#[test]
+fn to_fixed_small() {
+    assert_eq!(Big::new(5.0, -1).to_fixed(2), "0.50");
+    assert_eq!(Big::new(1.6, -1).to_fixed(2), "0.16");
+    // rounding bumps the last visible digit
+    assert_eq!(Big::new(1.6, -2).to_fixed(2), "0.02");
+    assert_eq!(Big::new(9.96, -1).to_fixed(1), "1.0");
+    assert_eq!(Big::new(1.6, -3).to_fixed(2), "0.00");
+    assert_eq!(Big::new(-1.6, -2).to_fixed(2), "-0.02");
+    assert_eq!(Big::new(1.0, i64::MIN).to_fixed(2), "0.00");
+    assert_eq!(Big::new(1.0, -(1 << 32)).to_fixed(2), "0.00");
+
+    for e in -12..=-1 {
+        let expected = format!("{:.6}", format!("1.6e{e}").parse::<f64>().unwrap());
+        assert_eq!(Big::new(1.6, e).to_fixed(6), expected, "1.6e{e}");
+    }
+}
+
+#[test]
+fn to_fixed_trimmed() {
+    assert_eq!(b(1.5).to_fixed_trimmed(4), "1.5");
+    assert_eq!(b(2).to_fixed_trimmed(4), "2");
+    assert_eq!(b(100).to_fixed_trimmed(2), "100");
+    assert_eq!(b(1.23456).to_fixed_trimmed(3), "1.235");
+    assert_eq!(b(-0.25).to_fixed_trimmed(4), "-0.25");
+    assert_eq!(b(-0.0001).to_fixed_trimmed(2), "0");
+    assert_eq!(
+        Big::new(1.5, 20).to_fixed_trimmed(2),
+        "150000000000000000000"
+    );
+    assert_eq!(b(1.5).to_fixed_trimmed(0), "2");
+    assert_eq!(Big::Zero.to_fixed_trimmed(3), "0");
+    assert_eq!(Big::NaN.to_fixed_trimmed(3), "NaN");
+    assert_eq!(crate::NEG_INFINITY.to_fixed_trimmed(3), "-inf");
+}
+
+#[test]
+fn to_fixed_min_width() {
+    assert_eq!(b(42).to_fixed_min_width(2, 4), "0042.00");
+    assert_eq!(b(-42).to_fixed_min_width(2, 4), "-0042.00");
+    assert_eq!(b(12345.678).to_fixed_min_width(1, 4), "12345.7");
+    assert_eq!(b(7).to_fixed_min_width(0, 3), "007");
+    assert_eq!(b(0.25).to_fixed_min_width(2, 3), "000.25");
+    assert_eq!(b(42).to_fixed_min_width(2, 0), "42.00");
+    assert_eq!(Big::Zero.to_fixed_min_width(1, 3), "000.0");
+    assert_eq!(Big::NaN.to_fixed_min_width(1, 3), "NaN");
+    assert_eq!(crate::NEG_INFINITY.to_fixed_min_width(1, 5), "-inf");
 }
 
 #[test]
 fn to_exponential() {
     assert_eq!(b(-6789.6789).to_exponential(2), "-6.79e3");
     assert_eq!(b(0).to_exponential(2), "0.00");
+    assert_eq!(b(0).to_exponential(0), "0");
     assert_eq!(Big::new(1.23, -1234).to_exponential(2), "1.23e-1234");
     assert_eq!(Big::NaN.to_exponential(2), "NaN");
 }
+
+#[test]
+fn to_exponential_carry() {
+    assert_eq!(Big::new(9.999, 5).to_exponential(2), "1.00e6");
+    assert_eq!(Big::new(-9.999, 5).to_exponential(2), "-1.00e6");
+    assert_eq!(Big::new(9.96, -3).to_exponential(0), "1e-2");
+    assert_eq!(Big::new(9.994, 5).to_exponential(2), "9.99e5");
+    assert_eq!(
+        Big::new(9.999, i64::MAX).to_exponential(2),
+        "1.00e9223372036854775808"
+    );
+}
+
+#[test]
+fn to_fixed_carry() {
+    assert_eq!(Big::new(9.9999, 2).to_fixed(1), "1000.0");
+    assert_eq!(Big::new(-9.9999, 2).to_fixed(1), "-1000.0");
+    assert_eq!(Big::new(9.9999, 14).to_fixed(0), "999990000000000");
+    assert_eq!(Big::new(-9.96, -1).to_fixed(1), "-1.0");
+}
+
+#[test]
+fn to_exponential_rounded() {
+    let half = b(2.5);
+    assert_eq!(half.to_exponential_rounded(0, RoundMode::HalfEven), "2e0");
+    assert_eq!(half.to_exponential_rounded(0, RoundMode::HalfUp), "3e0");
+    assert_eq!(half.to_exponential_rounded(0, RoundMode::Down), "2e0");
+    assert_eq!(half.to_exponential_rounded(0, RoundMode::Up), "3e0");
+
+    let negative = Big::new(-1.125, 50);
+    assert_eq!(
+        negative.to_exponential_rounded(2, RoundMode::HalfEven),
+        "-1.12e50"
+    );
+    assert_eq!(
+        negative.to_exponential_rounded(2, RoundMode::HalfUp),
+        "-1.13e50"
+    );
+    assert_eq!(
+        negative.to_exponential_rounded(2, RoundMode::Down),
+        "-1.12e50"
+    );
+    assert_eq!(
+        negative.to_exponential_rounded(2, RoundMode::Up),
+        "-1.13e50"
+    );
+
+    assert_eq!(b(1.21).to_exponential_rounded(1, RoundMode::Up), "1.3e0");
+    assert_eq!(b(1.15).to_exponential_rounded(2, RoundMode::Down), "1.15e0");
+    assert_eq!(b(1.15).to_exponential_rounded(2, RoundMode::Up), "1.15e0");
+    assert_eq!(
+        b(4.35).to_exponential_rounded(1, RoundMode::HalfUp),
+        "4.4e0"
+    );
+    assert_eq!(
+        Big::new(9.99, i64::MAX).to_exponential_rounded(1, RoundMode::Up),
+        "1.0e9223372036854775808"
+    );
+    assert_eq!(
+        Big::new(1.5, 3).to_exponential_rounded(400, RoundMode::Down),
+        format!("1.5{}e3", "0".repeat(399))
+    );
+    assert_eq!(
+        Big::new(-9.5, 3).to_exponential_rounded(20, RoundMode::Up),
+        "-9.50000000000000000000e3"
+    );
+    assert_eq!(b(1.29).to_exponential_rounded(1, RoundMode::Down), "1.2e0");
+    assert_eq!(
+        b(9.96).to_exponential_rounded(1, RoundMode::HalfUp),
+        "1.0e1"
+    );
+    assert_eq!(b(-9.91).to_exponential_rounded(1, RoundMode::Up), "-1.0e1");
+
+    assert_eq!(Big::Zero.to_exponential_rounded(2, RoundMode::Up), "0.00");
+    assert_eq!(Big::NaN.to_exponential_rounded(2, RoundMode::Up), "NaN");
+}
+
+#[test]
+fn to_suffix() {
+    assert_eq!(b(999).to_suffix(2), "999.00");
+    assert_eq!(b(1000).to_suffix(2), "1.00K");
+    assert_eq!(b(999999).to_suffix(2), "1.00M");
+    assert_eq!(b(999499).to_suffix(2), "999.50K");
+    assert_eq!(b(999999).to_suffix(0), "1M");
+    assert_eq!(b(1e6).to_suffix(2), "1.00M");
+    assert_eq!(b(7.894e9).to_suffix(2), "7.89B");
+    assert_eq!(b(1e12).to_suffix(1), "1.0T");
+    assert_eq!(Big::new(4.5, 16).to_suffix(1), "45.0Qa");
+    assert_eq!(Big::new(1.0, 33).to_suffix(2), "1.00Dc");
+    assert_eq!(Big::new(9.99, 35).to_suffix(2), "999.00Dc");
+    assert_eq!(Big::new(1.0, 36).to_suffix(2), "1.00e36");
+    assert_eq!(b(-1500).to_suffix(2), "-1.50K");
+    assert_eq!(b(999.999).to_suffix(2), "1.00K");
+    assert_eq!(b(0.5).to_suffix(2), "0.50");
+    assert_eq!(b(12.345).to_suffix(1), "12.3");
+    assert_eq!(Big::Zero.to_suffix(2), "0");
+    assert_eq!(Big::NaN.to_suffix(2), "NaN");
+    assert_eq!(crate::NEG_INFINITY.to_suffix(2), "-inf");
+}
+
+#[test]
+fn to_scientific_name() {
+    assert_eq!(b(999999).to_scientific_name(2), "999999.00");
+    assert_eq!(b(999999.999).to_scientific_name(2), "1.00 million");
+    assert_eq!(b(-999999.999).to_scientific_name(2), "-1.00 million");
+    assert_eq!(b(999999.999).to_scientific_name(3), "999999.999");
+    assert_eq!(b(1e6).to_scientific_name(2), "1.00 million");
+    assert_eq!(b(2.5e9).to_scientific_name(2), "2.50 billion");
+    assert_eq!(b(-9.99e14).to_scientific_name(2), "-999.00 trillion");
+    assert_eq!(b(1234.5).to_scientific_name(1), "1234.5");
+    assert_eq!(Big::new(1.5, 63).to_scientific_name(1), "1.5 vigintillion");
+    assert_eq!(Big::new(1.5, 66).to_scientific_name(1), "1.5e66");
+    assert_eq!(
+        Big::new(1.5, 66).to_scientific_name_with(1, |number, places| number.to_nested(places)),
+        "1.5e66"
+    );
+    assert_eq!(
+        Big::new(1.0, 1_000_000)
+            .to_scientific_name_with(1, |number, places| number.to_nested(places)),
+        "e1000000.0"
+    );
+    assert_eq!(Big::Zero.to_scientific_name(2), "0");
+    assert_eq!(Big::NaN.to_scientific_name(2), "NaN");
+}
+
+#[test]
+fn to_exponential_grouped_exp() {
+    assert_eq!(
+        Big::new(1.2345, 1234567).to_exponential_grouped_exp(2, ','),
+        "1.23e1,234,567"
+    );
+    assert_eq!(
+        Big::new(-1.5, -1234567).to_exponential_grouped_exp(1, '.'),
+        "-1.5e-1.234.567"
+    );
+    assert_eq!(
+        Big::new(1.5, 123456).to_exponential_grouped_exp(1, ','),
+        "1.5e123,456"
+    );
+    assert_eq!(
+        Big::new(1.5, 999).to_exponential_grouped_exp(1, ','),
+        "1.5e999"
+    );
+    assert_eq!(b(1.5).to_exponential_grouped_exp(1, ','), "1.5e0");
+    assert_eq!(
+        Big::new(1.0, i64::MIN).to_exponential_grouped_exp(0, ' '),
+        "1e-9 223 372 036 854 775 808"
+    );
+    assert_eq!(
+        Big::new(9.999, 5).to_exponential_grouped_exp(2, ','),
+        "1.00e6"
+    );
+    assert_eq!(
+        Big::new(-9.999, 999).to_exponential_grouped_exp(2, ','),
+        "-1.00e1,000"
+    );
+    assert_eq!(Big::Zero.to_exponential_grouped_exp(1, ','), "0.0");
+    assert_eq!(Big::NaN.to_exponential_grouped_exp(1, ','), "NaN");
+}
+
+#[test]
+fn to_engineering() {
+    assert_eq!(b(1.23e4).to_engineering(1), "12.3e3");
+    assert_eq!(b(5e-4).to_engineering(0), "500e-6");
+    assert_eq!(b(1.5e-3).to_engineering(1), "1.5e-3");
+    assert_eq!(b(-2.5e-5).to_engineering(1), "-25.0e-6");
+    assert_eq!(b(999.96).to_engineering(1), "1.0e3");
+    assert_eq!(b(7).to_engineering(2), "7.00e0");
+    assert_eq!(Big::Zero.to_engineering(2), "0.00");
+    assert_eq!(Big::NaN.to_engineering(2), "NaN");
+}
+
+#[test]
+fn to_binary_prefix() {
+    assert_eq!(b(1536).to_binary_prefix(1), "1.5Ki");
+    assert_eq!(b(1023).to_binary_prefix(1), "1023.0");
+    assert_eq!(b(1023.99).to_binary_prefix(1), "1.0Ki");
+    assert_eq!(b(1024).to_binary_prefix(2), "1.00Ki");
+    assert_eq!(b(2097152).to_binary_prefix(1), "2.0Mi");
+    assert_eq!(b(-3.0 * 1024f64.powi(3)).to_binary_prefix(0), "-3Gi");
+    assert_eq!(b(1024f64.powi(8)).to_binary_prefix(1), "1.0Yi");
+    assert_eq!(Big::new(1.0, 27).to_binary_prefix(1), "827.2Yi");
+    assert_eq!(Big::new(1.2, 27).to_binary_prefix(1), "992.6Yi");
+    assert_eq!(b(1024f64.powi(9)).to_binary_prefix(1), "1.2e27");
+    assert_eq!(Big::new(1.0, 100).to_binary_prefix(2), "1.00e100");
+    assert_eq!(b(0.5).to_binary_prefix(2), "0.50");
+    assert_eq!(Big::Zero.to_binary_prefix(2), "0");
+    assert_eq!(Big::NaN.to_binary_prefix(2), "NaN");
+    assert_eq!(crate::POS_INFINITY.to_binary_prefix(2), "+inf");
+}
+
+#[test]
+fn to_letter_notation() {
+    assert_eq!(b(999).to_letter_notation(2), "999.00");
+    assert_eq!(b(1000).to_letter_notation(2), "1.00aa");
+    assert_eq!(b(999999).to_letter_notation(2), "1.00ab");
+    assert_eq!(b(1e6).to_letter_notation(2), "1.00ab");
+    assert_eq!(b(-1.5e9).to_letter_notation(1), "-1.5ac");
+    assert_eq!(Big::new(1.0, 78).to_letter_notation(2), "1.00az");
+    assert_eq!(Big::new(1.0, 81).to_letter_notation(2), "1.00ba");
+    assert_eq!(Big::new(1.0, 84).to_letter_notation(2), "1.00bb");
+    assert_eq!(Big::new(1.0, 676 * 3).to_letter_notation(2), "1.00zz");
+    assert_eq!(Big::new(1.0, 677 * 3).to_letter_notation(2), "1.00aaa");
+    assert_eq!(Big::new(1.0, 678 * 3).to_letter_notation(2), "1.00aab");
+    assert_eq!(
+        Big::new(1.0, i64::MAX).to_letter_notation(0),
+        "10afepvdflpdcjub"
+    );
+    assert_eq!(b(0.5).to_letter_notation(2), "0.50");
+    assert_eq!(Big::Zero.to_letter_notation(2), "0");
+    assert_eq!(Big::NaN.to_letter_notation(2), "NaN");
+    assert_eq!(crate::POS_INFINITY.to_letter_notation(2), "+inf");
+}
+
+#[test]
+fn to_exponential_aligned() {
+    let positive = Big::new(1.23, 5).to_exponential_aligned(2);
+    let negative = Big::new(-1.23, 5).to_exponential_aligned(2);
+    assert_eq!(positive, " 1.23e5");
+    assert_eq!(negative, "-1.23e5");
+    assert_eq!(positive.len(), negative.len());
+    assert_eq!(b(0).to_exponential_aligned(2), " 0.00");
+    assert_eq!(Big::NaN.to_exponential_aligned(2), "NaN");
+}
+
+#[test]
+fn to_duration_string() {
+    assert_eq!(b(0).to_duration_string(), "0s");
+    assert_eq!(b(0.2).to_duration_string(), "0s");
+    assert_eq!(b(45).to_duration_string(), "45s");
+    assert_eq!(b(59.7).to_duration_string(), "1m");
+    assert_eq!(b(274320).to_duration_string(), "3d 4h 12m");
+    assert_eq!(b(31_536_001).to_duration_string(), "1y 1s");
+    assert_eq!(b(-90).to_duration_string(), "-1m 30s");
+    assert_eq!(b(-0.2).to_duration_string(), "0s");
+    assert_eq!(Big::new(3.1536, 13).to_duration_string(), "1.0e6 years");
+    assert_eq!(Big::new(7.2, 17).to_duration_string(), "2.3e10 years");
+    assert_eq!(Big::NaN.to_duration_string(), "NaN");
+    assert_eq!(crate::POS_INFINITY.to_duration_string(), "+inf");
+}
+
+#[test]
+fn to_duration() {
+    use crate::TimeUnit::*;
+
+    assert_eq!(b(11545).to_duration(Hour, Minute), "3h 12m");
+    assert_eq!(b(274345).to_duration(Day, Minute), "3d 4h 12m");
+    assert_eq!(b(180000).to_duration(Hour, Hour), "50h");
+    assert_eq!(b(180000).to_duration(Hour, Second), "50h");
+    assert_eq!(b(3 * 86400 + 90).to_duration(Hour, Minute), "72h 2m");
+    assert_eq!(b(29.9).to_duration(Hour, Minute), "0m");
+    assert_eq!(b(30).to_duration(Hour, Minute), "1m");
+    assert_eq!(b(-5400).to_duration(Hour, Minute), "-1h 30m");
+    assert_eq!(b(-10).to_duration(Hour, Minute), "0m");
+    assert_eq!(b(90).to_duration(Second, Minute), "1m 30s");
+    assert_eq!(b(2e12).to_duration(Day, Second), "2.3e7 days");
+    assert_eq!(b(6e7).to_duration(Minute, Minute), "1.0e6 minutes");
+    assert_eq!(Big::Zero.to_duration(Day, Hour), "0h");
+    assert_eq!(Big::NaN.to_duration(Day, Hour), "NaN");
+    assert_eq!(crate::NEG_INFINITY.to_duration(Day, Hour), "-inf");
+}
+
+#[test]
+fn components() {
+    assert_eq!(
+        b(-6789.6789).components(2),
+        DisplayComponents {
+            sign: '-',
+            mantissa: "6.79".to_string(),
+            exponent: 3,
+            is_special: None,
+        }
+    );
+    assert_eq!(
+        Big::new(1.5, -1234).components(0),
+        DisplayComponents {
+            sign: '+',
+            mantissa: "2".to_string(),
+            exponent: -1234,
+            is_special: None,
+        }
+    );
+
+    assert_eq!(
+        Big::new(-9.999, 5).components(2),
+        DisplayComponents {
+            sign: '-',
+            mantissa: "1.00".to_string(),
+            exponent: 6,
+            is_special: None,
+        }
+    );
+
+    let zero = b(0).components(2);
+    assert_eq!(
+        (zero.sign, zero.mantissa.as_str(), zero.exponent),
+        ('+', "0.00", 0)
+    );
+
+    let nan = Big::NaN.components(2);
+    assert_eq!(nan.is_special, Some("NaN"));
+    assert_eq!(nan.mantissa, "");
+    let neg_inf = crate::NEG_INFINITY.components(2);
+    assert_eq!((neg_inf.sign, neg_inf.is_special), ('-', Some("inf")));
+}
+
+#[test]
+fn to_exponential_capped_exp() {
+    assert_eq!(
+        Big::new(1.2345, 99).to_exponential_capped_exp(2, 2),
+        "1.23e99"
+    );
+    assert_eq!(
+        Big::new(1.2345, 100).to_exponential_capped_exp(2, 2),
+        "1.23e1.0e2"
+    );
+    assert_eq!(
+        Big::new(-1.2345, -100).to_exponential_capped_exp(2, 2),
+        "-1.23e-1.0e2"
+    );
+    assert_eq!(
+        Big::new(1.2345, 1234567).to_exponential_capped_exp(2, 7),
+        "1.23e1234567"
+    );
+    assert_eq!(
+        Big::new(1.2345, 1234567).to_exponential_capped_exp(2, 6),
+        "1.23e1.23457e6"
+    );
+    assert_eq!(
+        Big::new(1.2345, 1234567).to_exponential_capped_exp(1, 0),
+        "1.2e1e6"
+    );
+    assert_eq!(
+        Big::new(9.999, 1234567).to_exponential_capped_exp(2, 2),
+        "1.00e1.2e6"
+    );
+    assert_eq!(
+        Big::new(9.999, 99).to_exponential_capped_exp(2, 2),
+        "1.00e1.0e2"
+    );
+    assert_eq!(b(0).to_exponential_capped_exp(2, 2), "0.00");
+    assert_eq!(b(5).to_exponential_capped_exp(2, 0), "5.00e0");
+    assert_eq!(Big::NaN.to_exponential_capped_exp(2, 2), "NaN");
+}
+
+#[test]
+fn to_nested() {
+    assert_eq!(Big::new(1.2345, 999999).to_nested(2), "1.23e999999");
+    assert_eq!(Big::new(1.0, 1000000).to_nested(2), "e1000000.00");
+    assert_eq!(Big::new(-2.0, 1234567).to_nested(2), "-e1234567.30");
+    assert_eq!(Big::new(2.0, -1234567).to_nested(1), "e-1234566.7");
+    assert_eq!(Big::new(1.0, 1_000_000_000_000_000).to_nested(2), "ee15.00");
+    assert_eq!(Big::new(9.9, i64::MAX).to_nested(2), "ee18.96");
+    assert_eq!(Big::new(1.0, i64::MIN).to_nested(2), "e-e18.96");
+    assert_eq!(b(0).to_nested(2), "0.00");
+    assert_eq!(Big::NaN.to_nested(2), "NaN");
+}
+
+#[test]
+fn format_column() {
+    let values = [
+        b(1234),
+        b(-5),
+        Big::new(1.5, -100),
+        b(0),
+        Big::NaN,
+        crate::NEG_INFINITY,
+        Big::new(-9.87, 123456),
+    ];
+    let column = super::format_column(&values, 2);
+    assert!(column.iter().all(|row| row.len() == column[0].len()));
+    assert_eq!(
+        column,
+        vec![
+            " 1.23e3     ",
+            "-5.00e0     ",
+            " 1.50e-100  ",
+            " 0.00e0     ",
+            "         NaN",
+            "        -inf",
+            "-9.87e123456",
+        ]
+    );
+
+    assert_eq!(
+        super::format_column(&[Big::new(9.999, 5), b(-2)], 2),
+        vec![" 1.00e6", "-2.00e0"]
+    );
+    assert!(super::format_column(&[], 2).is_empty());
+    assert_eq!(
+        super::format_column(&[Big::NaN, b(1)], 0),
+        vec![" NaN", " 1e0"]
+    );
+}
+
+#[test]
+fn format_with() {
+    use super::notation::{
+        EngineeringNotation, LetterNotation, Notation, ScientificNotation, SuffixNotation,
+    };
+
+    let number = Big::new(1.2346, 7);
+    assert_eq!(number.format_with(&ScientificNotation, 2), "1.23e7");
+    assert_eq!(number.format_with(&SuffixNotation, 2), "12.35M");
+    assert_eq!(number.format_with(&LetterNotation, 2), "12.35ab");
+    assert_eq!(number.format_with(&EngineeringNotation, 2), "12.35e6");
+
+    // chosen at runtime
+    let notations: [&dyn Notation; 2] = [&ScientificNotation, &EngineeringNotation];
+    let formatted: Vec<String> = notations
+        .iter()
+        .map(|notation| b(-4.56e-5).format_with(*notation, 1))
+        .collect();
+    assert_eq!(formatted, vec!["-4.6e-5", "-45.6e-6"]);
+
+    assert_eq!(b(999.99).format_with(&EngineeringNotation, 1), "1.0e3");
+    for notation in notations {
+        assert_eq!(Big::Zero.format_with(notation, 2), "0");
+        assert_eq!(Big::NaN.format_with(notation, 2), "NaN");
+        assert_eq!(crate::NEG_INFINITY.format_with(notation, 2), "-inf");
+    }
+
+    // implementers only format numbers
+    struct Raw;
+    impl Notation for Raw {
+        fn format_number(&self, value: &Big, _places: usize) -> String {
+            format!("{value:?}")
+        }
+    }
+    assert_eq!(b(5).format_with(&Raw, 0), "Number { m: 5.0, e: 0 }");
+    assert_eq!(Big::NaN.format_with(&Raw, 0), "NaN");
+}
+
+#[test]
+fn debug_str() {
+    assert_eq!(
+        Big::new(1.23, 45).debug_str(),
+        "Number { m: 1.23, e: 45 } (≈1.23e45)"
+    );
+    assert_eq!(
+        Big::new(-9.999, -3).debug_str(),
+        "Number { m: -9.999, e: -3 } (≈-1.00e-2)"
+    );
+    assert_eq!(Big::Zero.debug_str(), "Zero (0)");
+    assert_eq!(Big::NaN.debug_str(), "NaN (NaN)");
+    assert_eq!(crate::POS_INFINITY.debug_str(), "Infinity(Positive) (+inf)");
+    assert_eq!(crate::NEG_INFINITY.debug_str(), "Infinity(Negative) (-inf)");
+}
+
+#[test]
+fn to_delta_string() {
+    assert_eq!(Big::from(123000).to_delta_string(2), "+1.23e5");
+    assert_eq!(Big::from(-4500).to_delta_string(1), "-4.5e3");
+    assert_eq!(Big::new(9.999, -3).to_delta_string(2), "+1.00e-2");
+    assert_eq!(Big::Zero.to_delta_string(2), "0");
+    assert_eq!(crate::POS_INFINITY.to_delta_string(2), "+inf");
+    assert_eq!(crate::NEG_INFINITY.to_delta_string(2), "-inf");
+    assert_eq!(Big::NaN.to_delta_string(2), "NaN");
+
+    assert_eq!(Big::from(123000).delta_sign(), DeltaSign::Positive);
+    assert_eq!(Big::from(-4500).delta_sign(), DeltaSign::Negative);
+    assert_eq!(Big::Zero.delta_sign(), DeltaSign::Zero);
+    assert_eq!(crate::POS_INFINITY.delta_sign(), DeltaSign::Positive);
+    assert_eq!(crate::NEG_INFINITY.delta_sign(), DeltaSign::Negative);
+    assert_eq!(Big::NaN.delta_sign(), DeltaSign::NaN);
+}
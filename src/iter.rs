@@ -0,0 +1,114 @@
+use std::iter::{Product, Sum};
+
+use crate::{shift_mantissa, Big, SIG_DIGITS};
+
+/// Sum `values`, normalizing only once at the end.
+///
+/// The mantissas are accumulated at the largest exponent seen so far, so a growing sum never
+/// loses track of its magnitude. Mantissas that are [SIG_DIGITS] or more orders of magnitude smaller
+/// are negligible and skipped. Returns NaN as soon as a NaN is encountered.
+fn sum_normalized_once(values: impl Iterator<Item = Big>) -> Big {
+    let mut specials = Big::Zero;
+    let mut m_total = 0.0;
+    let mut max_e: Option<i64> = None;
+
+    for value in values {
+        match value {
+            Big::NaN => return Big::NaN,
+            Big::Number { m, e } => match max_e {
+                Some(current) if e > current => {
+                    let delta = e.saturating_sub(current);
+                    m_total = match delta < SIG_DIGITS {
+                        // dividing by an exact power of 10 avoids the rounding error of a negative powi
+                        true => m_total / 10f64.powi(delta as i32) + m,
+                        false => m,
+                    };
+                    max_e = Some(e);
+                }
+                Some(current) => {
+                    let delta = current.saturating_sub(e);
+                    if delta < SIG_DIGITS {
+                        m_total += m / 10f64.powi(delta as i32);
+                    }
+                }
+                None => {
+                    m_total = m;
+                    max_e = Some(e);
+                }
+            },
+            Big::Zero => {}
+            infinity => specials += infinity,
+        }
+    }
+
+    match max_e {
+        Some(e) => specials + Big::new(m_total, e),
+        None => specials,
+    }
+}
+
+impl Sum for Big {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        sum_normalized_once(iter)
+    }
+}
+
+impl<'a> Sum<&'a Big> for Big {
+    fn sum<I: Iterator<Item = &'a Big>>(iter: I) -> Self {
+        sum_normalized_once(iter.copied())
+    }
+}
+
+/// Multiply `values`, starting from 1.
+///
+/// The exponents are summed as an [prim@i128], so they can not overflow before the end, e.g. if a huge
+/// factor is followed by a tiny one. Zero, NaN and Infinities follow the rules of multiplication.
+fn product_normalized_once(values: impl Iterator<Item = Big>) -> Big {
+    // collects the factors that are not numbers
+    let mut specials = Big::from(1);
+    let mut m_product = 1.0;
+    let mut e_total: i128 = 0;
+
+    for value in values {
+        match value {
+            Big::NaN => return Big::NaN,
+            Big::Number { m, e } => {
+                m_product *= m;
+                e_total += e as i128;
+                // the product of normalized mantissas only grows, keep it far away from overflowing
+                if m_product.abs() >= 1e280 {
+                    let log = m_product.abs().log10().floor() as i32;
+                    m_product = shift_mantissa(m_product, log);
+                    e_total += log as i128;
+                }
+            }
+            special => specials.mul_mut_unnormalized(special),
+        }
+    }
+
+    match specials {
+        // only the sign of the numbers matters for Infinities, minus times minus is plus
+        Big::Infinity(_) => {
+            specials.with_sign(specials.is_sign_negative() == m_product.is_sign_negative())
+        }
+        Big::Number { .. } => {
+            let mut product = Big::new_unnormalized(m_product, 0);
+            product.set_exponent_saturating(e_total);
+            product.normalize();
+            product
+        }
+        specials => specials,
+    }
+}
+
+impl Product for Big {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        product_normalized_once(iter)
+    }
+}
+
+impl<'a> Product<&'a Big> for Big {
+    fn product<I: Iterator<Item = &'a Big>>(iter: I) -> Self {
+        product_normalized_once(iter.copied())
+    }
+}
@@ -9,17 +9,33 @@
 //! a game genre which can feature very large numbers.
 
 use std::{
+    cmp::Ordering,
+    error::Error,
     f64,
     fmt::Display,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
 };
 
+mod aggregate;
+mod atomic;
 mod comparison;
 mod conversion;
+#[cfg(feature = "serde")]
+pub mod deserialize_finite;
 mod fmt_simple;
+mod iter;
 #[cfg(test)]
 mod tests;
 
+pub use aggregate::{
+    max_by_iter, median, min_by_iter, reduce, scale_all, std_dev, sum_pairwise, variance,
+    weighted_mean,
+};
+pub use atomic::BigAtomic;
+pub use comparison::TotalOrd;
+pub use conversion::{BigRepr, ParseError, TryFromBigError};
+pub use fmt_simple::{format_column, notation, DeltaSign, DisplayComponents, RoundMode, TimeUnit};
+
 /// # The Big Number Type
 ///
 /// A Number in the range of 10<sup>[i64::MIN]</sup>..10.0*10<sup>[i64::MAX]</sup> (exclusive).
@@ -29,7 +45,7 @@ mod tests;
 /// Create Numbers using [Big::new()] and [Big::from()].
 ///
 /// Operate on these numbers with regular operators: +, -, *, /
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Big {
     /// A normal number in the form of Mantissa * 10<sup>Exponent</sup>
@@ -50,7 +66,7 @@ pub enum Big {
 /// This type is used to describe if an Infinity is positive or negative.
 /// You will rarely use it yourself. You should look at [Big::is_pos_inf()] and [Big::is_neg_inf()] instead
 /// There are also [crate::POS_INFINITY] and [crate::NEG_INFINITY] for ease of use.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InfinityKind {
     /// Positive Infinity, also referred to as +inf
@@ -59,6 +75,73 @@ pub enum InfinityKind {
     Negative,
 }
 
+/// The Error returned by math operations that are not defined for their input, like [Big::try_sqrt()]
+#[derive(Debug, PartialEq)]
+pub enum MathError {
+    /// The operation is not defined for negative numbers
+    Negative,
+    /// The input is NaN
+    NaN,
+}
+
+impl Display for MathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Negative => write!(f, "Operation is not defined for negative numbers"),
+            Self::NaN => write!(f, "Operation is not defined for NaN"),
+        }
+    }
+}
+
+impl Error for MathError {}
+
+/// The outcome of [Big::normalize_reporting()], which tells if normalizing left the representable range
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NormalizeOutcome {
+    /// The number is in range, or it was Zero, NaN or an Infinity before
+    Ok,
+    /// The number was too large and saturated to +inf
+    SaturatedPositive,
+    /// The number was too large and saturated to -inf
+    SaturatedNegative,
+    /// The number was too small and underflowed to Zero
+    Underflowed,
+}
+
+/// An easing curve for [Big::ease_log()], which shapes the progress `t` within 0.0..=1.0
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Easing {
+    /// Constant speed
+    Linear,
+    /// Starts slow and speeds up, t<sup>2</sup>
+    QuadIn,
+    /// Starts fast and slows down, 1 - (1 - t)<sup>2</sup>
+    QuadOut,
+    /// Starts and ends slow, 3t<sup>2</sup> - 2t<sup>3</sup>
+    Smoothstep,
+}
+
+impl Easing {
+    /// Apply the curve to `t`, which is clamped to 0.0..=1.0 first
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Easing;
+    ///
+    /// assert_eq!(Easing::QuadIn.apply(0.5), 0.25);
+    /// assert_eq!(Easing::Smoothstep.apply(2.0), 1.0);
+    /// ```
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::QuadIn => t * t,
+            Self::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Self::Smoothstep => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
 /// A Constant Describing Positive Infinity
 pub const POS_INFINITY: Big = Big::Infinity(InfinityKind::Positive);
 /// A Constant Describing Negative Infinity
@@ -66,6 +149,31 @@ pub const NEG_INFINITY: Big = Big::Infinity(InfinityKind::Negative);
 const SIG_DIGITS: i64 = 15;
 
 impl Big {
+    /// The smallest positive normalized number, 1.0 * 10<sup>[i64::MIN]</sup>
+    ///
+    /// Results with a smaller magnitude underflow to [Big::Zero].
+    pub const MIN_POSITIVE: Big = Big::Number {
+        m: 1.0,
+        e: i64::MIN,
+    };
+
+    /// The largest finite number, just below 10.0 * 10<sup>[i64::MAX]</sup>
+    ///
+    /// Results with a larger magnitude overflow to [POS_INFINITY].
+    pub const MAX: Big = Big::Number {
+        // the largest f64 below 10.0
+        m: 10.0 - 8.0 * f64::EPSILON,
+        e: i64::MAX,
+    };
+
+    /// The smallest finite number, just above -10.0 * 10<sup>[i64::MAX]</sup>
+    ///
+    /// Results with a larger magnitude overflow to [NEG_INFINITY].
+    pub const MIN: Big = Big::Number {
+        m: -(10.0 - 8.0 * f64::EPSILON),
+        e: i64::MAX,
+    };
+
     /// Create a new Instance. The Number is normalized automatically.
     ///
     /// # Example
@@ -97,6 +205,47 @@ impl Big {
         }
     }
 
+    /// Return the mantissa of a [Big::Number], or [None] for Zero, NaN and Infinities.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(1234).mantissa(), Some(1.234));
+    /// assert_eq!(Big::Zero.mantissa(), None);
+    /// ```
+    pub fn mantissa(&self) -> Option<f64> {
+        self.to_parts().map(|(m, _)| m)
+    }
+
+    /// Return the exponent of a [Big::Number], or [None] for Zero, NaN and Infinities.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(1234).exponent(), Some(3));
+    /// assert_eq!(Big::NaN.exponent(), None);
+    /// ```
+    pub fn exponent(&self) -> Option<i64> {
+        self.to_parts().map(|(_, e)| e)
+    }
+
+    /// Return the mantissa and exponent of a [Big::Number], or [None] for Zero, NaN and Infinities.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::new(1.5, 400).to_parts(), Some((1.5, 400)));
+    /// ```
+    pub fn to_parts(&self) -> Option<(f64, i64)> {
+        match self {
+            Self::Number { m, e } => Some((*m, *e)),
+            _ => None,
+        }
+    }
+
     /// Normalize the number so it is in a correct state.
     ///
     /// **Note:** Unless you used any `_unnormalized` method, you never need to call this manually.
@@ -104,7 +253,7 @@ impl Big {
         match *self {
             Self::Infinity(_) | Self::NaN | Self::Zero => return,
             Self::Number { m, .. } => match m {
-                m if m == 0.0 => {
+                0.0 => {
                     *self = Self::Zero;
                     return;
                 }
@@ -121,7 +270,21 @@ impl Big {
                     return;
                 }
                 // if the number is already normalized, we can skip everything below
-                m if (1.0..10.0).contains(&m) => return,
+                m if (1.0..10.0).contains(&m.abs()) => return,
+                // a product of two mantissas is below 100, a single division is enough
+                m if (10.0..100.0).contains(&m.abs()) => {
+                    if let Self::Number { m, e } = self {
+                        match e.checked_add(1) {
+                            Some(exponent) => {
+                                *m /= 10.0;
+                                *e = exponent;
+                            }
+                            None if m.is_sign_positive() => *self = POS_INFINITY,
+                            None => *self = NEG_INFINITY,
+                        }
+                    }
+                    return;
+                }
                 // see below
                 _ => {}
             },
@@ -132,7 +295,7 @@ impl Big {
             ref mut e,
         } = *self
         {
-            let log = m.abs().log10() as i64;
+            let log = m.abs().log10().floor() as i64;
 
             match log {
                 // might underflow to Zero
@@ -154,14 +317,63 @@ impl Big {
                 }
             }
 
-            let log = m.abs().log10().floor() as i64;
-            *m /= 10.0_f64.powi(log as i32);
+            *m = shift_mantissa(*m, log as i32);
             *e += log;
         }
     }
 
+    /// Normalize the number like [Big::normalize()], and report if it saturated to an Infinity or underflowed to Zero.
+    ///
+    /// A mantissa of 0.0 becomes Zero and a NaN mantissa becomes NaN without being reported,
+    /// while an infinite mantissa is reported as saturated.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::{Big, NormalizeOutcome};
+    ///
+    /// let mut number = Big::new_unnormalized(100.0, i64::MAX);
+    /// assert_eq!(number.normalize_reporting(), NormalizeOutcome::SaturatedPositive);
+    /// assert!(number.is_pos_inf());
+    /// ```
+    pub fn normalize_reporting(&mut self) -> NormalizeOutcome {
+        let was_nonzero = matches!(self, Self::Number { m, .. } if *m != 0.0 && !m.is_nan());
+        self.normalize();
+
+        match self {
+            Self::Infinity(InfinityKind::Positive) if was_nonzero => {
+                NormalizeOutcome::SaturatedPositive
+            }
+            Self::Infinity(InfinityKind::Negative) if was_nonzero => {
+                NormalizeOutcome::SaturatedNegative
+            }
+            Self::Zero if was_nonzero => NormalizeOutcome::Underflowed,
+            _ => NormalizeOutcome::Ok,
+        }
+    }
+
+    /// Return true if `self` is in a normalized state.
+    ///
+    /// This is the case if the mantissa of a [Big::Number] is within ±1.0..10.0, which is guaranteed
+    /// unless you used any `_unnormalized` method. Zero, NaN and Infinities are always normalized.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert!(Big::new(42.0, 0).is_normalized());
+    /// assert!(!Big::new_unnormalized(42.0, 0).is_normalized());
+    /// ```
+    pub fn is_normalized(&self) -> bool {
+        match self {
+            Self::Number { m, .. } => (1.0..10.0).contains(&m.abs()),
+            Self::Infinity(_) | Self::NaN | Self::Zero => true,
+        }
+    }
+
     /// This will invert the sign of `self`, modifying it in-place.
     ///
+    /// The exponent is never touched, so this is safe for any exponent.
+    ///
     /// # Example
     /// ```
     /// use bignum_ig::Big;
@@ -203,12 +415,13 @@ impl Big {
                 return;
             }
             (Self::Number { .. } | Self::Zero, Self::Infinity(kind)) => {
-                *self = Self::Infinity(kind.clone())
+                *self = Self::Infinity(*kind)
             }
 
             // Zero
             (Self::Zero, other) => {
-                *self = other.clone();
+                *self = *other;
+                return;
             }
             (Self::Number { .. }, Self::Zero) => return,
 
@@ -225,7 +438,7 @@ impl Big {
             },
         ) = (self, rhs)
         {
-            let delta = other_e - *e;
+            let delta = other_e.saturating_sub(*e);
             match delta {
                 // ..=-SIG_DIGITS produced a syntax error
                 _delta if delta <= -SIG_DIGITS => {}
@@ -273,8 +486,9 @@ impl Big {
 
             // Zero
             (Self::Zero, other) => {
-                *self = other.clone();
+                *self = *other;
                 self.neg_mut();
+                return;
             }
             (Self::Number { .. }, Self::Zero) => return,
 
@@ -291,12 +505,13 @@ impl Big {
             },
         ) = (self, rhs)
         {
-            let delta = other_e - *e;
+            let delta = other_e.saturating_sub(*e);
             match delta {
                 // ..=-SIG_DIGITS produced a syntax error
                 _delta if delta <= -SIG_DIGITS => {}
                 _delta if delta >= SIG_DIGITS => {
-                    *m = other_m;
+                    // self is negligible, so the result is -other
+                    *m = -other_m;
                     *e = other_e;
                 }
                 delta => {
@@ -323,11 +538,14 @@ impl Big {
             (Self::NaN, _) | (_, Self::NaN) => *self = Self::NaN,
 
             // Infinities
-            (Self::Infinity(_), Self::Infinity(InfinityKind::Positive)) => return,
-            (_, Self::Infinity(InfinityKind::Negative)) => *self = NEG_INFINITY,
             (Self::Zero, Self::Infinity(_)) | (Self::Infinity(_), Self::Zero) => *self = Self::NaN,
-            (Self::Number { .. }, Self::Infinity(kind)) => *self = Self::Infinity(kind.clone()),
-            (Self::Infinity(_), Self::Number { .. }) => return,
+            (Self::Infinity(_), _) | (_, Self::Infinity(_)) => {
+                // minus times minus is plus
+                *self = match self.is_sign_negative() == rhs.is_sign_negative() {
+                    true => POS_INFINITY,
+                    false => NEG_INFINITY,
+                };
+            }
 
             // Zero
             (Self::Zero, _) => return,
@@ -344,10 +562,11 @@ impl Big {
                 m: other_m,
                 e: other_e,
             },
-        ) = (self, rhs)
+        ) = (&mut *self, rhs)
         {
             *m *= other_m;
-            *e += other_e;
+            let exponent = *e as i128 + other_e as i128;
+            self.set_exponent_saturating(exponent);
         };
     }
 
@@ -382,252 +601,1510 @@ impl Big {
                 m: other_m,
                 e: other_e,
             },
-        ) = (self, rhs)
+        ) = (&mut *self, rhs)
         {
+            // keep the quotient in 1.0..10.0, so normalizing it does not round a second time
+            let shift = i128::from(m.abs() < other_m.abs());
+            if shift == 1 {
+                *m *= 10.0;
+            }
             *m /= other_m;
-            *e -= other_e;
+            let exponent = *e as i128 - other_e as i128 - shift;
+            self.set_exponent_saturating(exponent);
         };
     }
 
-    /// Take the absolute value of `self`, modifying it in-place
+    /// Return `self` + `other` * `factor`, scaling the mantissa of `other` directly instead of
+    /// constructing an intermediate [Big] from `factor`.
     ///
     /// # Example
     /// ```
     /// use bignum_ig::Big;
     ///
-    /// let mut number = Big::from(-42);
-    /// number.abs_mut();
-    /// assert_eq!(number, Big::from(42));
+    /// let result = Big::from(100).scaled_add(0.5, &Big::from(20));
+    /// assert_eq!(result, Big::from(110));
     /// ```
-    pub fn abs_mut(&mut self) {
-        if let Self::Number { m, .. } = self {
-            *m = m.abs();
-        }
+    pub fn scaled_add(&self, factor: f64, other: &Big) -> Self {
+        let scaled = match other {
+            Self::Number { m, e } if (m * factor).is_finite() => Self::new(m * factor, *e),
+            // the mantissa would overflow, or other is Zero, NaN or an Infinity
+            other => *other * Self::from(factor),
+        };
+
+        let mut result = *self;
+        result += scaled;
+        result
     }
 
-    /// Take the absolute value of `self`, creating a new Instance
+    /// Return a copy of `self` with the exponent wrapped around modulo `period`, keeping the mantissa.
+    ///
+    /// This is meant as a transform for cyclic displays and has no mathematical meaning.
+    /// Zero, NaN and Infinities are returned unchanged. A `period` that is not positive results in NaN.
     ///
     /// # Example
     /// ```
     /// use bignum_ig::Big;
     ///
-    /// assert_eq!(Big::from(-42).abs(), Big::from(42));
-    /// assert_eq!(Big::from(42).abs(), Big::from(42));
+    /// assert_eq!(Big::new(1.5, 1234).wrap_exponent(1000), Big::new(1.5, 234));
+    /// assert_eq!(Big::new(1.5, -3).wrap_exponent(1000), Big::new(1.5, 997));
     /// ```
-    pub fn abs(&self) -> Self {
-        let mut result = self.clone();
-        result.abs_mut();
-        result
+    pub fn wrap_exponent(&self, period: i64) -> Self {
+        match self {
+            _ if period <= 0 => Self::NaN,
+            Self::Number { m, e } => Self::new_unnormalized(*m, e.rem_euclid(period)),
+            other => *other,
+        }
     }
 
-    /// Return true if `self` is NaN
-    ///
-    /// Use this method because [Big::NaN] != [Big::NaN]
+    /// Apply stacked bonuses to `base`, computing `base` * (1 + sum of `additive`) * product of `multiplicative`
     ///
     /// # Example
     /// ```
     /// use bignum_ig::Big;
     ///
-    /// assert!(Big::NaN.is_nan());
+    /// let additive = [Big::from(0.5), Big::from(1.5)];
+    /// let multiplicative = [Big::from(2), Big::from(1.5)];
+    /// let result = Big::apply_bonuses(&Big::from(10), &additive, &multiplicative);
+    /// assert_eq!(result, Big::from(90));
     /// ```
-    pub fn is_nan(&self) -> bool {
-        matches!(self, Self::NaN)
+    pub fn apply_bonuses(base: &Big, additive: &[Big], multiplicative: &[Big]) -> Self {
+        let mut additive_total = Self::from(1);
+        for bonus in additive {
+            additive_total.add_mut_unnormalized(*bonus);
+            // normalize after every step, the growing total would be discarded as negligible otherwise
+            additive_total.normalize();
+        }
+
+        let mut result = *base;
+        result.mul_mut_unnormalized(additive_total);
+        for factor in multiplicative {
+            // normalize after every step, the mantissa might overflow otherwise
+            result.normalize();
+            result.mul_mut_unnormalized(*factor);
+        }
+        result.normalize();
+        result
     }
 
-    /// Return true if `self` is +inf
+    /// Add `gain` to `self`, capped at `cap`, i.e. min(`self` + `gain`, `cap`)
     ///
-    /// Use this method because +inf != +inf
+    /// A sum that overflows to Infinity is clamped to `cap` as well. If either value is NaN, the result is NaN.
     ///
     /// # Example
     /// ```
-    /// assert!(bignum_ig::POS_INFINITY.is_pos_inf());
+    /// use bignum_ig::Big;
+    ///
+    /// let cap = Big::from(100);
+    /// assert_eq!(Big::from(90).add_capped(&Big::from(20), &cap), Big::from(100));
+    /// assert_eq!(Big::from(50).add_capped(&Big::from(20), &cap), Big::from(70));
     /// ```
-    pub fn is_pos_inf(&self) -> bool {
-        matches!(self, Self::Infinity(InfinityKind::Positive))
+    pub fn add_capped(&self, gain: &Big, cap: &Big) -> Self {
+        let sum = *self + *gain;
+        match cap.is_nan() {
+            true => Self::NaN,
+            false if sum > *cap => *cap,
+            false => sum,
+        }
     }
 
-    /// Return true if `self` is -inf
+    /// Spend `amount` from `self`, computing max(`self` - `amount`, 0), so a balance never becomes negative.
     ///
-    /// Use this method because -inf != -inf
+    /// Spending Infinity leaves Zero, while an infinite balance stays infinite. Spending Infinity from an
+    /// infinite balance is undefined and results in NaN, just like subtraction. See [Big::try_spend()]
+    /// to only spend affordable amounts.
     ///
     /// # Example
     /// ```
-    /// assert!(bignum_ig::NEG_INFINITY.is_neg_inf());
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(100).spend(&Big::from(30)), Big::from(70));
+    /// assert_eq!(Big::from(100).spend(&Big::from(130)), Big::Zero);
     /// ```
-    pub fn is_neg_inf(&self) -> bool {
-        matches!(self, Self::Infinity(InfinityKind::Negative))
+    pub fn spend(&self, amount: &Big) -> Self {
+        let remaining = *self - *amount;
+        match remaining < Self::Zero {
+            true => Self::Zero,
+            false => remaining,
+        }
     }
 
-    /// Return true if `self` is Zero
+    /// Spend `amount` from `self` only if it is affordable, returning the remaining balance.
     ///
-    /// Comparing `self` to [Big::Zero] is also possible
+    /// Returns [None] if `self` < `amount`, or if the two can not be compared, e.g. if either is NaN.
     ///
     /// # Example
     /// ```
     /// use bignum_ig::Big;
     ///
-    /// assert!(Big::from(0).is_zero());
+    /// assert_eq!(Big::from(100).try_spend(&Big::from(30)), Some(Big::from(70)));
+    /// assert_eq!(Big::from(100).try_spend(&Big::from(130)), None);
     /// ```
-    pub fn is_zero(&self) -> bool {
-        self == &Big::Zero
+    pub fn try_spend(&self, amount: &Big) -> Option<Self> {
+        match self >= amount {
+            true => Some(*self - *amount),
+            false => None,
+        }
     }
 
-    /// Return the logarithm to the base of 10 of `self`
+    /// Compute the prestige gain floor((`value` / `requirement`)<sup>`exponent`</sup>), capped at `cap`
+    ///
+    /// The power is computed in log space with [Big::powf()], so it does not overflow.
+    /// Returns Zero if `value` < `requirement`.
+    /// `requirement` is expected to be positive, otherwise the result is NaN.
     ///
     /// # Example
     /// ```
     /// use bignum_ig::Big;
     ///
-    /// let log = Big::from(100).log10();
-    /// assert_eq!(log, 2.0);
+    /// let gain = Big::prestige_gain(&Big::new(1.0, 20), &Big::new(1.0, 10), 0.5, &Big::new(1.0, 100));
+    /// assert_eq!(gain, Big::from(100000));
     /// ```
-    pub fn log10(self) -> f64 {
-        match self {
-            Self::Number { m, e } => m.log10() + e as f64,
-            Self::Infinity(InfinityKind::Negative) => f64::NAN,
-            Self::Infinity(InfinityKind::Positive) => f64::INFINITY,
-            Self::Zero | Self::NaN => f64::NAN,
+    pub fn prestige_gain(value: &Big, requirement: &Big, exponent: f64, cap: &Big) -> Self {
+        if value < requirement {
+            return Self::Zero;
+        }
+
+        // powf works in log space, dividing first keeps exact ratios exact
+        let mut gain = (*value / *requirement).powf(exponent);
+
+        gain = match gain {
+            // gains below 1 are floored to Zero, the exponent might not fit into powi
+            Self::Number { e, .. } if e < 0 => Self::Zero,
+            // numbers with e >= SIG_DIGITS have no fractional part left
+            Self::Number { m, e } if e < SIG_DIGITS => {
+                Self::from((m * 10f64.powi(e as i32)).floor())
+            }
+            other => other,
+        };
+
+        match gain > *cap {
+            true => *cap,
+            false => gain,
         }
     }
 
-    /// Return the natural logarithm of `self`
+    /// Return how many whole times `divisor` fits into `self`, which is floor(`self` / `divisor`) clamped
+    /// to the range of a [prim@u64]. Negative quotients are clamped to 0.
+    ///
+    /// Returns [None] if `divisor` is Zero or if either operand is NaN or an Infinity.
     ///
     /// # Example
     /// ```
     /// use bignum_ig::Big;
     ///
-    /// let ln = Big::from(f64::exp(2.0)).ln();
-    /// assert_eq!(ln, 2.0);
+    /// assert_eq!(Big::from(100).floor_div_count(&Big::from(30)), Some(3));
+    /// assert_eq!(Big::new(1.0, 100).floor_div_count(&Big::from(3)), Some(u64::MAX));
+    /// assert_eq!(Big::from(100).floor_div_count(&Big::Zero), None);
     /// ```
-    pub fn ln(self) -> f64 {
-        match self.log10() {
-            log if log.is_normal() => log / f64::consts::LOG10_E,
-            log => log,
+    pub fn floor_div_count(&self, divisor: &Big) -> Option<u64> {
+        match (self, divisor) {
+            (Self::Zero, Self::Number { .. }) => Some(0),
+            (
+                Self::Number { m, e },
+                Self::Number {
+                    m: other_m,
+                    e: other_e,
+                },
+            ) => {
+                let m = m / other_m;
+                // u64::MAX is below 10^20, m is at least 0.1
+                let quotient = match *e as i128 - *other_e as i128 {
+                    e if e > 20 => f64::INFINITY * m,
+                    e if e < -2 => 0.0,
+                    e => m * 10f64.powi(e as i32),
+                };
+                // casting saturates at 0 and u64::MAX
+                Some(quotient.floor() as u64)
+            }
+            _ => None,
         }
     }
 
-    /// Return the logarithm of any base of `self`
+    /// Linearly map `self` from the range `old_min`..`old_max` onto the range `new_min`..`new_max`
+    ///
+    /// If `old_min` == `old_max`, the old range is empty and `new_min` is returned.
+    /// Infinities follow the rules of the regular operators, so an infinite range usually results in NaN.
     ///
     /// # Example
     /// ```
     /// use bignum_ig::Big;
     ///
-    /// let log = Big::from(256).log(16.0);
-    /// assert_eq!(log, 2.0);
+    /// let value = Big::from(5).rescale(&Big::from(0), &Big::from(10), &Big::from(0), &Big::from(100));
+    /// assert_eq!(value, Big::from(50));
     /// ```
-    pub fn log(self, base: f64) -> f64 {
-        if base.is_normal() {
-            self.ln() / base.ln()
-        } else {
-            f64::NAN
+    pub fn rescale(&self, old_min: &Big, old_max: &Big, new_min: &Big, new_max: &Big) -> Self {
+        if old_min == old_max {
+            return *new_min;
         }
+
+        let position = *self - *old_min;
+        let old_range = *old_max - *old_min;
+        let new_range = *new_max - *new_min;
+        *new_min + position * new_range / old_range
     }
 
-    /// Raise `self` to `power` and modify it in-place.
+    /// Look up `level` in a table of `(level, cost)` breakpoints, interpolating linearly in log space
+    /// between the surrounding breakpoints.
+    ///
+    /// `table` is expected to be sorted by level and to contain positive costs, otherwise the result is NaN.
+    /// Levels outside the table are clamped to the first or last cost.
+    /// Returns NaN if `table` is empty or `level` is NaN.
     ///
     /// # Example
     /// ```
     /// use bignum_ig::Big;
     ///
-    /// let mut number = Big::from(16);
-    /// number.powf_mut(2.0);
-    /// assert_eq!(number, Big::from(256));
+    /// let table = [(0.0, Big::from(10)), (10.0, Big::from(1000))];
+    /// assert_eq!(Big::interp_table(5.0, &table), Big::from(100));
+    /// assert_eq!(Big::interp_table(20.0, &table), Big::from(1000));
     /// ```
-    pub fn powf_mut(&mut self, power: f64) {
-        if let Self::Zero = self {
-            if power.is_normal() {
-                return;
-            }
+    pub fn interp_table(level: f64, table: &[(f64, Big)]) -> Self {
+        let (Some((first_level, first_cost)), Some((last_level, last_cost))) =
+            (table.first(), table.last())
+        else {
+            return Self::NaN;
+        };
+
+        if level.is_nan() {
+            return Self::NaN;
+        }
+        if level <= *first_level {
+            return *first_cost;
+        }
+        if level >= *last_level {
+            return *last_cost;
         }
 
-        let result_log10 = self.abs().log10() * power;
+        // the first breakpoint above level, which is never the first one
+        let upper = table.partition_point(|(breakpoint, _)| *breakpoint <= level);
+        let (lower_level, lower_cost) = &table[upper - 1];
+        let (upper_level, upper_cost) = &table[upper];
+        if level == *lower_level {
+            return *lower_cost;
+        }
 
-        match result_log10 {
-            f64::NEG_INFINITY => *self = Self::NaN,
-            f64::INFINITY => *self = POS_INFINITY,
-            log if log.is_nan() => *self = Self::NaN,
-            // result_log10 may over/underflow as an i64, handle it
-            log if log < i64::MIN as f64 => *self = Self::Zero,
-            log if log > i64::MAX as f64 => *self = POS_INFINITY,
-            // normaliazion shouldn't be required here, since m will be between 1.0 and < 10.0
-            log => {
-                if let Self::Number { m, e } = self {
-                    *m = 10.0_f64.powf(log % 1.0);
-                    // minus times minus is plus
-                    if log % 2.0 == 0.0 {
-                        *m = m.abs();
-                    }
-                    *e = log as i64;
-                }
-            }
-        };
+        let t = (level - lower_level) / (upper_level - lower_level);
+        let lower_log = lower_cost.log10();
+        let upper_log = upper_cost.log10();
+        let mut result = Self::Zero;
+        result.set_from_log10(lower_log + (upper_log - lower_log) * t);
+        result
     }
 
-    /// Raise `self` to `power`, returning a new Instance
+    /// Map the position `t` of a log-scale slider to a value between `min` and `max`,
+    /// computing 10<sup>lerp(log10(`min`), log10(`max`), `t`)</sup>.
+    ///
+    /// `t` is clamped to 0.0..=1.0. Both bounds must be positive, since their logarithms are interpolated.
+    /// If either bound is not positive (including Zero and NaN), the result is NaN.
     ///
     /// # Example
     /// ```
     /// use bignum_ig::Big;
     ///
-    /// assert_eq!(Big::from(16).powf(2.0), Big::from(256));
+    /// let value = Big::from_log_slider(0.5, &Big::from(1), &Big::from(10000));
+    /// assert_eq!(value, Big::from(100));
     /// ```
-    pub fn powf(&self, power: f64) -> Self {
-        let mut result = self.clone();
-        result.powf_mut(power);
-        result
+    pub fn from_log_slider(t: f64, min: &Big, max: &Big) -> Self {
+        match *min > Self::Zero && *max > Self::Zero {
+            true => Self::ease_log(min, max, t, Easing::Linear),
+            false => Self::NaN,
+        }
     }
 
-    /// This will put the remainder of `self` % `rhs` into `self` without normalizing the result.
+    /// Interpolate between `start` and `end` in log space, with the progress `t` shaped by `easing`.
     ///
-    /// **Caution:** Only use this if you are absolutely sure of what you are doing and need every bit of performance!
-    /// You will have to call [Big::normalize()] yourself at some point to prevent bugs.
+    /// This moves through the orders of magnitude evenly, e.g. for animated counters.
+    /// `t` is clamped to 0.0..=1.0, so `start` and `end` are returned exactly at the ends.
+    /// `start` and `end` are expected to be positive, otherwise the result is NaN in between.
     ///
-    /// You will most likely want to use the %= or % operator instead, which will normalize the result automatically.
-    pub fn remainder_mut_unnormalized(&mut self, rhs: &Big) {
-        match (&self, rhs) {
-            (Self::NaN, _) | (_, Self::NaN) => return,
-            (_, Self::Zero) => *self = Self::NaN,
-            (Self::Infinity(_), Self::Infinity(_)) => *self = Self::NaN,
-            (Self::Zero, _) => return,
-            (Self::Number { .. }, Self::Infinity(_)) => return,
-            (Self::Infinity(_), Self::Number { .. }) => *self = Self::NaN,
-            // See below
-            (Self::Number { .. }, Self::Number { .. }) => {}
-        }
-
-        if let (
-            Self::Number { m, e },
-            Self::Number {
-                m: other_m,
-                e: other_e,
-            },
-        ) = (self, rhs)
-        {
-            let other_m_normalized = other_m * 10_f64.powi((*other_e - *e) as i32);
-            *m = match other_m_normalized {
-                f64::INFINITY => *m,
-                0.0 => 0.0,
-                value => *m % value,
+    /// # Example
+    /// ```
+    /// use bignum_ig::{Big, Easing};
+    ///
+    /// let value = Big::ease_log(&Big::from(10), &Big::from(1000), 0.5, Easing::Smoothstep);
+    /// assert_eq!(value, Big::from(100));
+    /// ```
+    pub fn ease_log(start: &Big, end: &Big, t: f64, easing: Easing) -> Self {
+        match easing.apply(t) {
+            t if t.is_nan() => Self::NaN,
+            0.0 => *start,
+            1.0 => *end,
+            t => {
+                let start_log = start.log10();
+                let end_log = end.log10();
+                let mut result = Self::Zero;
+                result.set_from_log10(start_log + (end_log - start_log) * t);
+                result
             }
         }
     }
-}
-
-impl AddAssign for Big {
-    fn add_assign(&mut self, rhs: Self) {
-        self.add_mut_unnormalized(rhs);
-        self.normalize();
-    }
-}
-
-impl Add for Big {
-    type Output = Big;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        let mut result = self.clone();
-        result += rhs;
-        result
-    }
+    /// Take the absolute value of `self`, modifying it in-place
+    ///
+    /// The exponent is never touched, so this is safe for any exponent.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::from(-42);
+    /// number.abs_mut();
+    /// assert_eq!(number, Big::from(42));
+    /// ```
+    pub fn abs_mut(&mut self) {
+        if let Self::Number { m, .. } = self {
+            *m = m.abs();
+        }
+    }
+
+    /// Take the absolute value of `self`, creating a new Instance
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(-42).abs(), Big::from(42));
+    /// assert_eq!(Big::from(42).abs(), Big::from(42));
+    /// ```
+    pub fn abs(&self) -> Self {
+        let mut result = *self;
+        result.abs_mut();
+        result
+    }
+
+    /// Apply an [prim@f64] function like [f64::floor()] to a number that is small enough to have a fractional part
+    fn apply_to_small(&mut self, f: fn(f64) -> f64) {
+        if let Self::Number { m, e } = *self {
+            if e >= SIG_DIGITS {
+                return;
+            }
+            // anything smaller than this is within 0..1, which also keeps the exponent in i32 range
+            let e = e.max(-300);
+            // dividing by an exact power of 10 avoids the rounding error of a negative powi
+            let value = match e < 0 {
+                true => m / 10f64.powi(-e as i32),
+                false => m * 10f64.powi(e as i32),
+            };
+            *self = Self::from(f(value));
+        }
+    }
+
+    /// Round `self` down to the next integer, modifying it in-place.
+    ///
+    /// Numbers of magnitude 10<sup>15</sup> or more are already integers and stay unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::from(-2.1);
+    /// number.floor_mut();
+    /// assert_eq!(number, Big::from(-3));
+    /// ```
+    pub fn floor_mut(&mut self) {
+        self.apply_to_small(f64::floor);
+    }
+
+    /// Round `self` down to the next integer, creating a new Instance
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(2.7).floor(), Big::from(2));
+    /// ```
+    pub fn floor(&self) -> Self {
+        let mut result = *self;
+        result.floor_mut();
+        result
+    }
+
+    /// Round `self` up to the next integer, modifying it in-place.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::from(2.1);
+    /// number.ceil_mut();
+    /// assert_eq!(number, Big::from(3));
+    /// ```
+    pub fn ceil_mut(&mut self) {
+        self.apply_to_small(f64::ceil);
+    }
+
+    /// Round `self` up to the next integer, creating a new Instance
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(-2.1).ceil(), Big::from(-2));
+    /// ```
+    pub fn ceil(&self) -> Self {
+        let mut result = *self;
+        result.ceil_mut();
+        result
+    }
+
+    /// Round `self` to the nearest integer, with ties away from zero, modifying it in-place.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::from(-2.5);
+    /// number.round_mut();
+    /// assert_eq!(number, Big::from(-3));
+    /// ```
+    pub fn round_mut(&mut self) {
+        self.apply_to_small(f64::round);
+    }
+
+    /// Round `self` to the nearest integer, with ties away from zero, creating a new Instance
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(2.5).round(), Big::from(3));
+    /// ```
+    pub fn round(&self) -> Self {
+        let mut result = *self;
+        result.round_mut();
+        result
+    }
+
+    /// Round `self` towards zero to the next integer, modifying it in-place.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::from(2.7);
+    /// number.trunc_mut();
+    /// assert_eq!(number, Big::from(2));
+    /// ```
+    pub fn trunc_mut(&mut self) {
+        self.apply_to_small(f64::trunc);
+    }
+
+    /// Round `self` towards zero to the next integer, creating a new Instance
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(-2.7).trunc(), Big::from(-2));
+    /// ```
+    pub fn trunc(&self) -> Self {
+        let mut result = *self;
+        result.trunc_mut();
+        result
+    }
+
+    /// Return the fractional part of `self`, which is `self` minus [Big::trunc()].
+    ///
+    /// Like [f64::fract()], the result has the sign of `self`. Numbers of magnitude 10<sup>15</sup> or more
+    /// have no fractional part, so the result is Zero. Infinities have no fractional part either and result in NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(2.25).fract(), Big::from(0.25));
+    /// assert_eq!(Big::from(-2.25).fract(), Big::from(-0.25));
+    /// ```
+    pub fn fract(&self) -> Self {
+        match self {
+            Self::Number { e, .. } if *e >= SIG_DIGITS => Self::Zero,
+            // within ±0..1, everything is the fractional part
+            Self::Number { e, .. } if *e < 0 => *self,
+            Self::Number { .. } => {
+                let mut result = *self;
+                result.apply_to_small(f64::fract);
+                result
+            }
+            Self::Infinity(_) => Self::NaN,
+            Self::NaN | Self::Zero => *self,
+        }
+    }
+
+    /// Return true if `self` is an integer, which is the case if [Big::fract()] is Zero.
+    ///
+    /// Zero is an integer, Infinities and NaN are not.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert!(Big::from(5).is_integer());
+    /// assert!(!Big::from(5.5).is_integer());
+    /// ```
+    pub fn is_integer(&self) -> bool {
+        match self {
+            Self::Number { .. } | Self::Zero => self.fract().is_zero(),
+            Self::Infinity(_) | Self::NaN => false,
+        }
+    }
+
+    /// Return the magnitude of `self` with a positive sign if `positive` is true, or a negative sign otherwise.
+    ///
+    /// Infinities take the requested sign as well, while Zero and NaN are returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(42).with_sign(false), Big::from(-42));
+    /// assert_eq!(Big::from(-42).with_sign(true), Big::from(42));
+    /// ```
+    pub fn with_sign(&self, positive: bool) -> Self {
+        match self {
+            Self::Number { m, e } => Self::Number {
+                m: if positive { m.abs() } else { -m.abs() },
+                e: *e,
+            },
+            Self::Infinity(_) if positive => POS_INFINITY,
+            Self::Infinity(_) => NEG_INFINITY,
+            slf => *slf,
+        }
+    }
+
+    /// Split `self` into its sign (-1, 0 or 1) and its absolute value.
+    ///
+    /// -inf results in `(-1, +inf)`. Zero and NaN have a sign of 0 and are returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(-42).sign_and_magnitude(), (-1, Big::from(42)));
+    /// assert_eq!(Big::Zero.sign_and_magnitude(), (0, Big::Zero));
+    /// ```
+    pub fn sign_and_magnitude(&self) -> (i32, Big) {
+        match self {
+            Self::Number { m, e } => (m.signum() as i32, Self::Number { m: m.abs(), e: *e }),
+            Self::Infinity(InfinityKind::Positive) => (1, POS_INFINITY),
+            Self::Infinity(InfinityKind::Negative) => (-1, POS_INFINITY),
+            slf => (0, *slf),
+        }
+    }
+
+    /// Return the sign of `self` as a [Big]: 1 for positive numbers and +inf, -1 for negative numbers and -inf.
+    /// Zero results in Zero and NaN results in NaN.
+    ///
+    /// Only the sign of the mantissa is checked, so this works for any exponent.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::new(-4.2, 1000).signum(), Big::from(-1));
+    /// assert_eq!(Big::Zero.signum(), Big::Zero);
+    /// ```
+    pub fn signum(&self) -> Self {
+        match self {
+            Self::Number { m, .. } if m.is_sign_positive() => Self::from(1),
+            Self::Number { .. } => Self::from(-1),
+            Self::Infinity(InfinityKind::Positive) => Self::from(1),
+            Self::Infinity(InfinityKind::Negative) => Self::from(-1),
+            slf => *slf,
+        }
+    }
+
+    /// Apply `f` to the mantissa of `self` and normalize the result, returning a new Instance.
+    /// Zero, NaN and Infinities are returned unchanged.
+    ///
+    /// This is an escape hatch for operations that are not provided directly. `f` must not assume that the
+    /// mantissa is within a particular range, e.g. it is negative for negative numbers.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::new(6.0, 100).map_mantissa(|m| m * 2.0), Big::new(1.2, 101));
+    /// ```
+    pub fn map_mantissa(&self, f: impl FnOnce(f64) -> f64) -> Self {
+        match self {
+            Self::Number { m, e } => Self::new(f(*m), *e),
+            slf => *slf,
+        }
+    }
+
+    /// Multiply `self` by 10<sup>`n`</sup> by shifting the exponent, modifying it in-place.
+    ///
+    /// If the exponent over- or underflows, `self` saturates to ± Infinity or Zero.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::from(42);
+    /// number.scale_by_pow10_mut(3);
+    /// assert_eq!(number, Big::from(42000));
+    /// ```
+    pub fn scale_by_pow10_mut(&mut self, n: i64) {
+        if let Self::Number { e, .. } = self {
+            let exponent = *e as i128 + n as i128;
+            self.set_exponent_saturating(exponent);
+        }
+    }
+
+    /// Multiply `self` by 10<sup>`n`</sup> by shifting the exponent, creating a new Instance
+    ///
+    /// If the exponent over- or underflows, the result saturates to ± Infinity or Zero.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(42).scale_by_pow10(-1), Big::from(4.2));
+    /// assert!(Big::new(1.0, i64::MAX).scale_by_pow10(1).is_pos_inf());
+    /// ```
+    pub fn scale_by_pow10(&self, n: i64) -> Self {
+        let mut result = *self;
+        result.scale_by_pow10_mut(n);
+        result
+    }
+
+    /// Multiply `self` by 10<sup>`n`</sup> like [Big::scale_by_pow10()], but return [None] instead of saturating
+    /// if the exponent over- or underflows.
+    ///
+    /// Zero, NaN and Infinities are returned unchanged, because they have no exponent.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(42).checked_scale_by_pow10(3), Some(Big::from(42000)));
+    /// assert_eq!(Big::new(1.0, i64::MAX).checked_scale_by_pow10(1), None);
+    /// ```
+    pub fn checked_scale_by_pow10(&self, n: i64) -> Option<Self> {
+        match self {
+            Self::Number { m, e } => e.checked_add(n).map(|e| Self::Number { m: *m, e }),
+            slf => Some(*slf),
+        }
+    }
+
+    /// Set the exponent of a [Big::Number], saturating to ± Infinity or Zero if it does not fit into an [prim@i64]
+    fn set_exponent_saturating(&mut self, exponent: i128) {
+        if let Self::Number { m, e } = self {
+            if let Ok(exponent) = i64::try_from(exponent) {
+                *e = exponent;
+                return;
+            }
+
+            // an unnormalized mantissa might bring the exponent back into range
+            let log = m.abs().log10().floor();
+            if log.is_finite() {
+                if let Ok(exponent) = i64::try_from(exponent + log as i128) {
+                    *m = shift_mantissa(*m, log as i32);
+                    *e = exponent;
+                    return;
+                }
+            }
+
+            *self = match (exponent < 0, m.is_sign_positive()) {
+                (true, _) => Self::Zero,
+                (false, true) => POS_INFINITY,
+                (false, false) => NEG_INFINITY,
+            };
+        }
+    }
+
+    /// Return true for positive numbers, +inf and Zero
+    ///
+    /// Zero counts as positive like `+0.0`, while NaN is neither positive nor negative.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert!(Big::from(42).is_sign_positive());
+    /// assert!(Big::Zero.is_sign_positive());
+    /// assert!(!Big::NaN.is_sign_positive());
+    /// ```
+    pub fn is_sign_positive(&self) -> bool {
+        match self {
+            Self::Number { m, .. } => m.is_sign_positive(),
+            Self::Infinity(kind) => *kind == InfinityKind::Positive,
+            Self::Zero => true,
+            Self::NaN => false,
+        }
+    }
+
+    /// Return true for negative numbers and -inf
+    ///
+    /// Zero counts as positive like `+0.0`, while NaN is neither positive nor negative.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert!(Big::new(-1.0, 1000).is_sign_negative());
+    /// assert!(!Big::Zero.is_sign_negative());
+    /// assert!(!Big::NaN.is_sign_negative());
+    /// ```
+    pub fn is_sign_negative(&self) -> bool {
+        match self {
+            Self::Number { m, .. } => m.is_sign_negative(),
+            Self::Infinity(kind) => *kind == InfinityKind::Negative,
+            Self::Zero | Self::NaN => false,
+        }
+    }
+
+    /// Return true if `self` is NaN
+    ///
+    /// Use this method because [Big::NaN] != [Big::NaN]
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert!(Big::NaN.is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        matches!(self, Self::NaN)
+    }
+
+    /// Return true if `self` is +inf
+    ///
+    /// Use this method because +inf != +inf
+    ///
+    /// # Example
+    /// ```
+    /// assert!(bignum_ig::POS_INFINITY.is_pos_inf());
+    /// ```
+    pub fn is_pos_inf(&self) -> bool {
+        matches!(self, Self::Infinity(InfinityKind::Positive))
+    }
+
+    /// Return true if `self` is -inf
+    ///
+    /// Use this method because -inf != -inf
+    ///
+    /// # Example
+    /// ```
+    /// assert!(bignum_ig::NEG_INFINITY.is_neg_inf());
+    /// ```
+    pub fn is_neg_inf(&self) -> bool {
+        matches!(self, Self::Infinity(InfinityKind::Negative))
+    }
+
+    /// Return true if `self` is Zero
+    ///
+    /// Comparing `self` to [Big::Zero] is also possible
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert!(Big::from(0).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self == &Big::Zero
+    }
+
+    /// Return true if `self` is neither an Infinity nor NaN
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert!(Big::new(1.0, 1000).is_finite());
+    /// assert!(Big::Zero.is_finite());
+    /// assert!(!bignum_ig::POS_INFINITY.is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        matches!(self, Self::Number { .. } | Self::Zero)
+    }
+
+    /// Return true if `self` is +inf or -inf
+    ///
+    /// # Example
+    /// ```
+    /// assert!(bignum_ig::NEG_INFINITY.is_infinite());
+    /// ```
+    pub fn is_infinite(&self) -> bool {
+        matches!(self, Self::Infinity(_))
+    }
+
+    /// Return true if `self` is neither Zero, an Infinity nor NaN
+    ///
+    /// Unlike [f64::is_normal()], there are no subnormal numbers, because the exponent is an [prim@i64].
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert!(Big::new(1.0, -1000).is_normal());
+    /// assert!(!Big::Zero.is_normal());
+    /// ```
+    pub fn is_normal(&self) -> bool {
+        matches!(self, Self::Number { .. })
+    }
+
+    /// Return the logarithm to the base of 10 of `self`
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let log = Big::from(100).log10();
+    /// assert_eq!(log, 2.0);
+    /// ```
+    pub fn log10(self) -> f64 {
+        match self {
+            Self::Number { m, e } => m.log10() + e as f64,
+            Self::Infinity(InfinityKind::Negative) => f64::NAN,
+            Self::Infinity(InfinityKind::Positive) => f64::INFINITY,
+            Self::Zero | Self::NaN => f64::NAN,
+        }
+    }
+
+    /// Return the symmetric logarithm to the base of 10 of `self`, which is defined for negative numbers too.
+    ///
+    /// For |`self`| >= 10, this is sign(`self`) * log10(|`self`|). Between -10 and 10, the result is linear
+    /// (`self` / 10) so it is continuous and passes through 0. This is useful for charts that span
+    /// huge negative and positive numbers.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(-1000).signed_log10(), -3.0);
+    /// assert_eq!(Big::from(5).signed_log10(), 0.5);
+    /// ```
+    pub fn signed_log10(&self) -> f64 {
+        match self {
+            Self::Number { m, e } if *e >= 1 => m.signum() * (m.abs().log10() + *e as f64),
+            // linear region, anything below 10^-400 is 0.0 as an f64 anyway
+            Self::Number { m, e } => m * 10f64.powi((*e).max(-400) as i32 - 1),
+            Self::Infinity(InfinityKind::Positive) => f64::INFINITY,
+            Self::Infinity(InfinityKind::Negative) => f64::NEG_INFINITY,
+            Self::Zero => 0.0,
+            Self::NaN => f64::NAN,
+        }
+    }
+
+    /// Return the natural logarithm of `self`
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let ln = Big::from(f64::exp(2.0)).ln();
+    /// assert_eq!(ln, 2.0);
+    /// ```
+    pub fn ln(self) -> f64 {
+        match self.log10() {
+            log if log.is_normal() => log / f64::consts::LOG10_E,
+            log => log,
+        }
+    }
+
+    /// Return ln(1 + `self`), which is accurate even if `self` is close to zero.
+    ///
+    /// If |`self`| < 10<sup>301</sup>, `self` fits into an [prim@f64] and [f64::ln_1p()] is used.
+    /// Anything below 10<sup>-400</sup> is 0.0 as an [prim@f64] though.
+    /// Larger numbers use [Big::ln()] instead, since adding 1 makes no difference for them.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::new(1.0, -20).ln_1p(), 1e-20);
+    /// assert_eq!((Big::from(1) + Big::new(1.0, -20)).ln(), 0.0);
+    /// ```
+    pub fn ln_1p(&self) -> f64 {
+        match self {
+            Self::Number { m, e } if *e <= 300 => {
+                let e = (*e).max(-400);
+                // dividing by an exact power of 10 avoids the rounding error of a negative powi
+                let x = match e < 0 {
+                    true => m / 10f64.powi(-e as i32),
+                    false => m * 10f64.powi(e as i32),
+                };
+                x.ln_1p()
+            }
+            Self::Zero => 0.0,
+            slf => slf.ln(),
+        }
+    }
+
+    /// Return log10(1 + `self`), which is accurate even if `self` is close to zero. See [Big::ln_1p()].
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(99).log10_1p(), 2.0);
+    /// ```
+    pub fn log10_1p(&self) -> f64 {
+        self.ln_1p() * f64::consts::LOG10_E
+    }
+
+    /// Return the logarithm of any base of `self`
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let log = Big::from(256).log(16.0);
+    /// assert_eq!(log, 2.0);
+    /// ```
+    pub fn log(self, base: f64) -> f64 {
+        if base.is_normal() {
+            self.ln() / base.ln()
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Return the index of the logarithmic bucket `self` falls into, where each bucket spans
+    /// `bucket_width_oom` orders of magnitude. This is floor(log10(`self`) / `bucket_width_oom`).
+    ///
+    /// Returns [None] if `self` is not a positive [Big::Number] or `bucket_width_oom` is not positive.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(500).log_bucket(1.0), Some(2));
+    /// assert_eq!(Big::new(1.0, 100).log_bucket(3.0), Some(33));
+    /// assert_eq!(Big::from(-500).log_bucket(1.0), None);
+    /// ```
+    pub fn log_bucket(&self, bucket_width_oom: f64) -> Option<i64> {
+        match self {
+            Self::Number { m, .. } if m.is_sign_positive() && bucket_width_oom > 0.0 => {
+                let bucket = (self.log10() / bucket_width_oom).floor();
+                bucket.is_finite().then_some(bucket as i64)
+            }
+            _ => None,
+        }
+    }
+
+    /// Return the index of the milestone `self` has reached, where a milestone is passed every `step_oom`
+    /// orders of magnitude. Compare the indices of consecutive frames to detect crossing a milestone.
+    ///
+    /// This is the exponent divided by `step_oom`, rounded down like [Big::log_bucket()], so it is exact
+    /// for any exponent. Returns [None] if `self` is not a positive [Big::Number] or `step_oom` is not positive.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(99999).milestone_index(3), Some(1));
+    /// assert_eq!(Big::from(100000).milestone_index(3), Some(1));
+    /// assert_eq!(Big::from(1000000).milestone_index(3), Some(2));
+    /// ```
+    pub fn milestone_index(&self, step_oom: i64) -> Option<i64> {
+        match self {
+            Self::Number { m, e } if m.is_sign_positive() && step_oom > 0 => {
+                Some(e.div_euclid(step_oom))
+            }
+            _ => None,
+        }
+    }
+
+    /// Overwrite `self` with 10<sup>`log`</sup>, modifying it in-place.
+    ///
+    /// This is the inverse of [Big::log10()] and is useful if you keep a running logarithm
+    /// and only occasionally need the actual number.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::Zero;
+    /// number.set_from_log10(3.0);
+    /// assert_eq!(number, Big::from(1000));
+    /// ```
+    pub fn set_from_log10(&mut self, log: f64) {
+        match log {
+            f64::INFINITY => *self = POS_INFINITY,
+            f64::NEG_INFINITY => *self = Self::Zero,
+            log if log.is_nan() => *self = Self::NaN,
+            // log may over/underflow as an i64, handle it
+            log if log < i64::MIN as f64 => *self = Self::Zero,
+            log if log >= i64::MAX as f64 => *self = POS_INFINITY,
+            log => {
+                let e = log.floor();
+                *self = Self::new_unnormalized(10.0_f64.powf(log - e), e as i64);
+                // 10^(log - e) may round up to 10.0
+                self.normalize();
+            }
+        }
+    }
+
+    /// Square `self`, modifying it in-place. This is exact and faster than [Big::powf_mut()] with a power of 2.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::from(-12);
+    /// number.square_mut();
+    /// assert_eq!(number, Big::from(144));
+    /// ```
+    pub fn square_mut(&mut self) {
+        match self {
+            Self::Number { m, e } => {
+                *m *= *m;
+                let exponent = *e as i128 * 2;
+                self.set_exponent_saturating(exponent);
+                self.normalize();
+            }
+            Self::Infinity(_) => *self = POS_INFINITY,
+            Self::NaN | Self::Zero => {}
+        }
+    }
+
+    /// Square `self`, returning a new Instance. This is exact and faster than [Big::powf()] with a power of 2.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(-12).square(), Big::from(144));
+    /// ```
+    pub fn square(&self) -> Self {
+        let mut result = *self;
+        result.square_mut();
+        result
+    }
+
+    /// Cube `self`, modifying it in-place. This is exact and faster than [Big::powf_mut()] with a power of 3,
+    /// and keeps the sign of negative numbers.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::from(-2);
+    /// number.cube_mut();
+    /// assert_eq!(number, Big::from(-8));
+    /// ```
+    pub fn cube_mut(&mut self) {
+        if let Self::Number { m, e } = self {
+            *m = *m * *m * *m;
+            let exponent = *e as i128 * 3;
+            self.set_exponent_saturating(exponent);
+            self.normalize();
+        }
+    }
+
+    /// Cube `self`, returning a new Instance. This is exact and faster than [Big::powf()] with a power of 3,
+    /// and keeps the sign of negative numbers.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(-2).cube(), Big::from(-8));
+    /// ```
+    pub fn cube(&self) -> Self {
+        let mut result = *self;
+        result.cube_mut();
+        result
+    }
+
+    /// Raise `self` to `power` and modify it in-place.
+    ///
+    /// Negative numbers can only be raised to integer powers, where odd powers keep the sign.
+    /// Any other power of a negative number results in NaN, see [Big::root_mut()] for odd roots.
+    ///
+    /// An infinite `power` behaves like [f64::powf()]: the result is Infinity or Zero depending on whether
+    /// |`self`| is larger or smaller than 1, and exactly 1 if |`self`| is 1.
+    ///
+    /// Zero raised to a positive power is Zero, and raised to a negative power is +inf. 0^0 is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::from(16);
+    /// number.powf_mut(2.0);
+    /// assert_eq!(number, Big::from(256));
+    /// ```
+    pub fn powf_mut(&mut self, power: f64) {
+        // exact results, which would lose precision by going through log10
+        if power == 1.0 {
+            return;
+        }
+        if power == 0.0 && matches!(self, Self::Number { .. }) {
+            *self = Self::from(1);
+            return;
+        }
+
+        if power.is_infinite() {
+            let magnitude = match self {
+                Self::NaN => return,
+                Self::Zero => Ordering::Less,
+                Self::Infinity(_) => Ordering::Greater,
+                // two numbers always compare
+                Self::Number { .. } => self
+                    .abs()
+                    .partial_cmp(&Self::from(1))
+                    .unwrap_or(Ordering::Equal),
+            };
+            // like f64::powf, the sign of self does not matter for an infinite power
+            *self = match (magnitude, power > 0.0) {
+                (Ordering::Equal, _) => Self::from(1),
+                (Ordering::Greater, true) | (Ordering::Less, false) => POS_INFINITY,
+                (Ordering::Greater, false) | (Ordering::Less, true) => Self::Zero,
+            };
+            return;
+        }
+
+        if let Self::Zero = self {
+            *self = match power {
+                power if power > 0.0 => Self::Zero,
+                power if power < 0.0 => POS_INFINITY,
+                // 0^0 and 0^NaN
+                _ => Self::NaN,
+            };
+            return;
+        }
+
+        // a negative base is only defined for integer powers, and only odd powers keep the sign
+        let negative = match self {
+            Self::Number { m, .. } => m.is_sign_negative(),
+            Self::Infinity(kind) => *kind == InfinityKind::Negative,
+            _ => false,
+        };
+        if negative && power.fract() != 0.0 {
+            *self = Self::NaN;
+            return;
+        }
+
+        if let Self::Number { m, e } = self {
+            // if self and the result fit into an f64, its powf is the most precise
+            if e.unsigned_abs() <= 300 {
+                // dividing by an exact power of 10 avoids the rounding error of a negative powi
+                let value = match *e < 0 {
+                    true => *m / 10f64.powi(-*e as i32),
+                    false => *m * 10f64.powi(*e as i32),
+                };
+                let result = value.powf(power);
+                if result.is_normal() {
+                    *self = Self::from(result);
+                    return;
+                }
+            }
+
+            // integer powers can be split into mantissa and exponent, like in square_mut,
+            // m^power stays within the range of an f64 for |power| <= 300
+            if power.fract() == 0.0 && power.abs() <= 300.0 {
+                *m = m.powi(power as i32);
+                let exponent = *e as i128 * power as i128;
+                self.set_exponent_saturating(exponent);
+                self.normalize();
+                return;
+            }
+        }
+
+        // Infinities follow from the logarithm as well, inf^-x is 10^-inf = 0
+        let result_log10 = self.with_sign(true).log10() * power;
+        self.set_from_log10(result_log10);
+
+        if negative && power % 2.0 != 0.0 {
+            self.neg_mut();
+        }
+    }
+
+    /// Raise `self` to `power`, returning a new Instance
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(16).powf(2.0), Big::from(256));
+    /// ```
+    pub fn powf(&self, power: f64) -> Self {
+        let mut result = *self;
+        result.powf_mut(power);
+        result
+    }
+
+    /// Take the reciprocal 1 / `self`, modifying it in-place.
+    ///
+    /// The mantissa is inverted and the exponent negated directly, which is cheaper than dividing.
+    /// Infinities result in Zero and NaN stays NaN. Unlike dividing by Zero, which results in NaN,
+    /// the reciprocal of Zero is +inf, like [Big::root()] and [Big::powf()] with a negative power.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::from(4);
+    /// number.recip_mut();
+    /// assert_eq!(number, Big::from(0.25));
+    /// ```
+    pub fn recip_mut(&mut self) {
+        match self {
+            Self::Number { m, e } => {
+                // keep the reciprocal in 1.0..10.0, so it does not need to be normalized
+                let shift = i128::from(m.abs() != 1.0);
+                *m = 10f64.powi(shift as i32) / *m;
+                let exponent = -(*e as i128) - shift;
+                self.set_exponent_saturating(exponent);
+            }
+            Self::Infinity(_) => *self = Self::Zero,
+            Self::Zero => *self = POS_INFINITY,
+            Self::NaN => {}
+        }
+    }
+
+    /// Take the reciprocal 1 / `self`, creating a new Instance.
+    ///
+    /// See [Big::recip_mut()] for details.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(-0.5).recip(), Big::from(-2));
+    /// ```
+    pub fn recip(&self) -> Self {
+        let mut result = *self;
+        result.recip_mut();
+        result
+    }
+
+    /// Take the square root of `self`, modifying it in-place.
+    ///
+    /// The exponent is halved directly instead of going through [Big::log10()], so this is exact for perfect
+    /// squares and works for any exponent. Negative numbers (including -inf) result in NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::from(144);
+    /// number.sqrt_mut();
+    /// assert_eq!(number, Big::from(12));
+    /// ```
+    pub fn sqrt_mut(&mut self) {
+        match self {
+            Self::Number { m, .. } if m.is_sign_negative() => *self = Self::NaN,
+            Self::Number { m, e } => {
+                // with an odd exponent, move one order of magnitude into the mantissa
+                *m = (*m * 10f64.powi(e.rem_euclid(2) as i32)).sqrt();
+                *e = e.div_euclid(2);
+                self.normalize();
+            }
+            Self::Infinity(InfinityKind::Negative) => *self = Self::NaN,
+            _ => {}
+        }
+    }
+
+    /// Take the square root of `self`, returning a new Instance. See [Big::sqrt_mut()].
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(144).sqrt(), Big::from(12));
+    /// assert!(Big::from(-144).sqrt().is_nan());
+    /// ```
+    pub fn sqrt(&self) -> Self {
+        let mut result = *self;
+        result.sqrt_mut();
+        result
+    }
+
+    /// Take the `degree`-th root of `self`, modifying it in-place.
+    ///
+    /// Unlike [Big::powf_mut()] with a power of 1 / `degree`, negative numbers have a negative root
+    /// for odd integer degrees. For any other degree, negative numbers result in NaN, just like a
+    /// `degree` of 0. For integer degrees, the exponent is divided directly like in [Big::sqrt_mut()],
+    /// so this works for any exponent.
+    ///
+    /// Infinities keep their sign for positive degrees (if the root is defined) and become Zero for
+    /// negative degrees, while Zero becomes Infinity for negative degrees.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// let mut number = Big::from(-27);
+    /// number.root_mut(3.0);
+    /// assert_eq!(number, Big::from(-3));
+    /// ```
+    pub fn root_mut(&mut self, degree: f64) {
+        if degree == 1.0 {
+            return;
+        }
+
+        let odd = degree.fract() == 0.0 && degree % 2.0 != 0.0;
+        let negative = match self {
+            Self::Number { m, .. } => m.is_sign_negative(),
+            Self::Infinity(kind) => *kind == InfinityKind::Negative,
+            _ => false,
+        };
+        if degree == 0.0 || degree.is_nan() || (negative && !odd) {
+            *self = Self::NaN;
+            return;
+        }
+
+        let mut result = self.with_sign(true);
+        let positive_degree = degree.abs();
+        match &mut result {
+            // splitting the exponent needs a mantissa of up to 10^(degree - 1), which must fit into an f64
+            Self::Number { m, e }
+                if positive_degree.fract() == 0.0 && (2.0..=300.0).contains(&positive_degree) =>
+            {
+                let positive_degree = positive_degree as i64;
+                let m_shifted = *m * 10f64.powi(e.rem_euclid(positive_degree) as i32);
+                *m = match positive_degree {
+                    2 => m_shifted.sqrt(),
+                    3 => m_shifted.cbrt(),
+                    degree => m_shifted.powf(1.0 / degree as f64),
+                };
+                *e = e.div_euclid(positive_degree);
+                result.normalize();
+            }
+            _ => result.powf_mut(1.0 / positive_degree),
+        }
+
+        // a negative degree is the reciprocal of the root, which is more precise than a negative power
+        if degree < 0.0 {
+            result = match result {
+                Self::Zero => POS_INFINITY,
+                result => Self::from(1) / result,
+            };
+        }
+
+        if negative {
+            result.neg_mut();
+        }
+        *self = result;
+    }
+
+    /// Take the `degree`-th root of `self`, returning a new Instance. See [Big::root_mut()].
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(-27).root(3.0), Big::from(-3));
+    /// assert!(Big::from(-16).root(4.0).is_nan());
+    /// ```
+    pub fn root(&self, degree: f64) -> Self {
+        let mut result = *self;
+        result.root_mut(degree);
+        result
+    }
+
+    /// Return the square root of `self`, or an Error if it is not defined.
+    ///
+    /// Unlike powers, this returns [MathError::Negative] for negative numbers (including -inf)
+    /// and [MathError::NaN] for NaN instead of silently producing NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::{Big, MathError};
+    ///
+    /// assert_eq!(Big::from(16).try_sqrt(), Ok(Big::from(4)));
+    /// assert_eq!(Big::from(-16).try_sqrt(), Err(MathError::Negative));
+    /// ```
+    pub fn try_sqrt(&self) -> Result<Big, MathError> {
+        match self {
+            Self::NaN => Err(MathError::NaN),
+            Self::Infinity(InfinityKind::Negative) => Err(MathError::Negative),
+            Self::Number { m, .. } if m.is_sign_negative() => Err(MathError::Negative),
+            slf => Ok(slf.sqrt()),
+        }
+    }
+
+    /// This will put the remainder of `self` % `rhs` into `self` without normalizing the result.
+    ///
+    /// **Caution:** Only use this if you are absolutely sure of what you are doing and need every bit of performance!
+    /// You will have to call [Big::normalize()] yourself at some point to prevent bugs.
+    ///
+    /// You will most likely want to use the %= or % operator instead, which will normalize the result automatically.
+    pub fn remainder_mut_unnormalized(&mut self, rhs: &Big) {
+        match (&self, rhs) {
+            (Self::NaN, _) | (_, Self::NaN) => *self = Self::NaN,
+            (_, Self::Zero) => *self = Self::NaN,
+            (Self::Infinity(_), Self::Infinity(_)) => *self = Self::NaN,
+            (Self::Zero, _) => return,
+            (Self::Number { .. }, Self::Infinity(_)) => return,
+            (Self::Infinity(_), Self::Number { .. }) => *self = Self::NaN,
+            // See below
+            (Self::Number { .. }, Self::Number { .. }) => {}
+        }
+
+        if let (
+            Self::Number { m, e },
+            Self::Number {
+                m: other_m,
+                e: other_e,
+            },
+        ) = (self, rhs)
+        {
+            // a delta outside of i32 results in 0 or inf, just like a large delta inside of it
+            let delta = other_e
+                .saturating_sub(*e)
+                .clamp(i32::MIN as i64, i32::MAX as i64);
+            let other_m_normalized = other_m * 10_f64.powi(delta as i32);
+            // a mantissa of ±0.0 is turned into Zero by normalize
+            *m = match other_m_normalized {
+                f64::INFINITY => *m,
+                0.0 => 0.0,
+                value => *m % value,
+            }
+        }
+    }
+
+    /// Return the remainder of `self` % `rhs`, or [None] if `rhs` is Zero
+    ///
+    /// # Example
+    /// ```
+    /// use bignum_ig::Big;
+    ///
+    /// assert_eq!(Big::from(8).checked_rem(&Big::from(3)), Some(Big::from(2)));
+    /// assert_eq!(Big::from(8).checked_rem(&Big::Zero), None);
+    /// ```
+    pub fn checked_rem(&self, rhs: &Big) -> Option<Big> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        let mut result = *self;
+        result.remainder_mut_unnormalized(rhs);
+        result.normalize();
+        Some(result)
+    }
+}
+
+/// Return `m` / 10<sup>`log`</sup>
+fn shift_mantissa(m: f64, log: i32) -> f64 {
+    match log {
+        // 10^1..10^22 are exact, but their inverses are not, so multiply for negative logs
+        -300..0 => m * 10.0_f64.powi(-log),
+        // subnormal mantissas, 10^-log would overflow
+        ..0 => m * 1e300 * 10.0_f64.powi(-log - 300),
+        _ => m / 10.0_f64.powi(log),
+    }
+}
+
+impl AddAssign for Big {
+    fn add_assign(&mut self, rhs: Self) {
+        self.add_mut_unnormalized(rhs);
+        self.normalize();
+    }
+}
+
+impl Add for Big {
+    type Output = Big;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
 }
 
 impl SubAssign for Big {
@@ -640,10 +2117,9 @@ impl SubAssign for Big {
 impl Sub for Big {
     type Output = Big;
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        let mut result = self.clone();
-        result -= rhs;
-        result
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
     }
 }
 
@@ -657,10 +2133,9 @@ impl MulAssign for Big {
 impl Mul for Big {
     type Output = Big;
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        let mut result = self.clone();
-        result *= rhs;
-        result
+    fn mul(mut self, rhs: Self) -> Self::Output {
+        self *= rhs;
+        self
     }
 }
 
@@ -674,10 +2149,9 @@ impl DivAssign for Big {
 impl Div for Big {
     type Output = Big;
 
-    fn div(self, rhs: Self) -> Self::Output {
-        let mut result = self.clone();
-        result /= rhs;
-        result
+    fn div(mut self, rhs: Self) -> Self::Output {
+        self /= rhs;
+        self
     }
 }
 
@@ -691,33 +2165,132 @@ impl RemAssign for Big {
 impl Rem for Big {
     type Output = Big;
 
-    fn rem(self, rhs: Self) -> Self::Output {
-        let mut result = self.clone();
-        result %= rhs;
-        result
+    fn rem(mut self, rhs: Self) -> Self::Output {
+        self %= rhs;
+        self
     }
 }
 
 impl Neg for Big {
     type Output = Big;
 
-    fn neg(self) -> Self::Output {
-        let mut result = self.clone();
-        result.neg_mut();
-        result
+    fn neg(mut self) -> Self::Output {
+        self.neg_mut();
+        self
     }
 }
 
+/// Implement `$trait` for every combination of [Big] and `&Big` that is not covered by value,
+/// applying `$apply` to a copy of the left operand and normalizing the result
+macro_rules! impl_ref_ops {
+    ($trait:ident, $method:ident, |$result:ident, $rhs:ident| $apply:expr) => {
+        impl $trait<&Big> for &Big {
+            type Output = Big;
+
+            fn $method(self, rhs: &Big) -> Self::Output {
+                let mut $result = *self;
+                let $rhs = *rhs;
+                $apply;
+                $result.normalize();
+                $result
+            }
+        }
+
+        impl $trait<Big> for &Big {
+            type Output = Big;
+
+            fn $method(self, rhs: Big) -> Self::Output {
+                $trait::$method(self, &rhs)
+            }
+        }
+
+        impl $trait<&Big> for Big {
+            type Output = Big;
+
+            fn $method(self, rhs: &Big) -> Self::Output {
+                $trait::$method(&self, rhs)
+            }
+        }
+    };
+}
+
+impl_ref_ops!(Add, add, |result, rhs| result.add_mut_unnormalized(rhs));
+impl_ref_ops!(Sub, sub, |result, rhs| result.sub_mut_unnormalized(rhs));
+impl_ref_ops!(Mul, mul, |result, rhs| result.mul_mut_unnormalized(rhs));
+impl_ref_ops!(Div, div, |result, rhs| result.div_mut_unnormalized(rhs));
+impl_ref_ops!(Rem, rem, |result, rhs| result
+    .remainder_mut_unnormalized(&rhs));
+
+/// Implement `$trait` and `$assign_trait` with a primitive right operand for every type in `$ty`,
+/// converting it with [Big::from()]
+macro_rules! impl_primitive_ops {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $($ty:ty),+) => {
+        $(
+            impl $trait<$ty> for Big {
+                type Output = Big;
+
+                fn $method(self, rhs: $ty) -> Self::Output {
+                    $trait::$method(self, Big::from(rhs))
+                }
+            }
+
+            impl $assign_trait<$ty> for Big {
+                fn $assign_method(&mut self, rhs: $ty) {
+                    $assign_trait::$assign_method(self, Big::from(rhs));
+                }
+            }
+        )+
+    };
+}
+
+impl_primitive_ops!(Add, add, AddAssign, add_assign, f64, i64, i32);
+impl_primitive_ops!(Sub, sub, SubAssign, sub_assign, f64, i64, i32);
+impl_primitive_ops!(Mul, mul, MulAssign, mul_assign, f64, i64, i32);
+impl_primitive_ops!(Div, div, DivAssign, div_assign, f64, i64, i32);
+impl_primitive_ops!(Rem, rem, RemAssign, rem_assign, f64, i64, i32);
+
 impl Display for Big {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // pad_integral takes care of the sign flag, width and fill
         match self {
             Big::Infinity(kind) => match kind {
-                InfinityKind::Positive => write!(f, "+inf"),
-                InfinityKind::Negative => write!(f, "-inf"),
+                InfinityKind::Positive if f.sign_plus() => f.pad_integral(true, "", "inf"),
+                InfinityKind::Positive => f.pad_integral(true, "", "+inf"),
+                InfinityKind::Negative => f.pad_integral(false, "", "inf"),
+            },
+            Big::NaN => match f.precision() {
+                None => f.pad("NaN"),
+                // pad would cut "NaN" down to the precision, which is meant for the mantissa
+                Some(_) => {
+                    let padding = f.width().unwrap_or(0).saturating_sub(3);
+                    let before = match f.align() {
+                        Some(std::fmt::Alignment::Right) => padding,
+                        Some(std::fmt::Alignment::Center) => padding / 2,
+                        _ => 0,
+                    };
+                    let fill = f.fill().to_string();
+                    write!(
+                        f,
+                        "{}NaN{}",
+                        fill.repeat(before),
+                        fill.repeat(padding - before)
+                    )
+                }
+            },
+            Big::Zero => match f.precision() {
+                Some(places) => f.pad_integral(true, "", &format!("{:.places$}", 0.0)),
+                None => f.pad_integral(true, "", "0"),
             },
-            Big::NaN => write!(f, "NaN"),
-            Big::Zero => write!(f, "0"),
-            Big::Number { m, e } => write!(f, "{}e{}", m, e),
+            Big::Number { m, e } => {
+                let digits = match f.precision() {
+                    Some(places) => {
+                        let (mantissa, e) = fmt_simple::round_mantissa(m.abs(), *e, places);
+                        format!("{mantissa}e{e}")
+                    }
+                    None => format!("{}e{e}", m.abs()),
+                };
+                f.pad_integral(m.is_sign_positive(), "", &digits)
+            }
         }
     }
 }
@@ -1,4 +1,4 @@
-use std::f64;
+use std::{cmp::Ordering, f64};
 
 use crate::*;
 
@@ -59,6 +59,169 @@ fn conversion() {
     assert!(inf.is_neg_inf());
 }
 
+#[test]
+fn to_f64() {
+    assert_eq!(b(1234.5).to_f64(), 1234.5);
+    assert_eq!(b(-0.001).to_f64(), -0.001);
+    assert_eq!(Big::new(1.5, 300).to_f64(), 1.5e300);
+    assert_eq!(Big::new(-2.5, -300).to_f64(), -2.5e-300);
+
+    // the overflow boundary
+    assert_eq!(Big::new(1.7976931348623157, 308).to_f64(), f64::MAX);
+    assert_eq!(Big::new(1.8, 308).to_f64(), f64::INFINITY);
+    assert_eq!(Big::new(-1.0, 309).to_f64(), f64::NEG_INFINITY);
+    assert_eq!(Big::new(1.0, i64::MAX).to_f64(), f64::INFINITY);
+    assert_eq!(
+        f64::try_from(Big::new(1.7976931348623157, 308)),
+        Ok(f64::MAX)
+    );
+    assert_eq!(f64::try_from(Big::new(1.8, 308)), Err(TryFromBigError));
+    assert_eq!(f64::try_from(Big::new(-1.0, 1000)), Err(TryFromBigError));
+
+    // subnormal numbers and underflows
+    assert_eq!(
+        Big::new(2.2250738585072014, -308).to_f64(),
+        f64::MIN_POSITIVE
+    );
+    assert_eq!(Big::new(5.0, -324).to_f64(), 5e-324);
+    assert_eq!(Big::new(-4.94065645841247, -324).to_f64(), -5e-324);
+    assert_eq!(Big::new(1.0, 22).to_f64(), 1e22);
+    assert_eq!(Big::new(1.0, -23).to_f64(), 1e-23);
+    assert_eq!(Big::new(1.0, -330).to_f64(), 0.0);
+    assert_eq!(Big::new(1.0, i64::MIN).to_f64(), 0.0);
+    assert_eq!(f64::try_from(Big::new(5.0, -324)), Ok(5e-324));
+
+    assert_eq!(Big::Zero.to_f64(), 0.0);
+    assert!(Big::NaN.to_f64().is_nan());
+    assert_eq!(POS_INFINITY.to_f64(), f64::INFINITY);
+    assert_eq!(f64::try_from(NEG_INFINITY), Ok(f64::NEG_INFINITY));
+    assert!(f64::try_from(Big::NaN).unwrap().is_nan());
+}
+
+#[test]
+fn conversion_from_integers() {
+    assert_eq!(Big::from(200u8), b(200));
+    assert_eq!(Big::from(-100i8), b(-100));
+    assert_eq!(Big::from(60000u16), b(60000));
+    assert_eq!(Big::from(-30000i16), b(-30000));
+    assert_eq!(Big::from(4_000_000_000u32), b(4e9));
+    assert_eq!(Big::from(1234usize), b(1234));
+    assert_eq!(Big::from(-1234isize), b(-1234));
+    assert_eq!(Big::from(0u64), Big::Zero);
+
+    let max = Big::from(u64::MAX);
+    assert!(max.is_normalized());
+    assert_eq!(max.e(), 19);
+    assert!((max.m() - 1.8446744073709552).abs() < 1e-15);
+
+    let max = Big::from(u128::MAX);
+    assert!(max.is_normalized());
+    assert_eq!(max.e(), 38);
+    assert!((max.m() - 3.402823669209385).abs() < 1e-15);
+
+    let min = Big::from(i128::MIN);
+    assert_eq!(min.e(), 38);
+    assert!((min.m() + 1.7014118346046923).abs() < 1e-15);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn deserialize_finite() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Save {
+        #[serde(with = "crate::deserialize_finite")]
+        gold: Big,
+    }
+    let load = |json: &str| serde_json::from_str::<Save>(json).unwrap().gold;
+
+    assert_eq!(load(r#"{ "gold": "NaN" }"#), Big::Zero);
+    assert_eq!(load(r#"{ "gold": { "Infinity": "Positive" } }"#), Big::MAX);
+    assert_eq!(load(r#"{ "gold": { "Infinity": "Negative" } }"#), Big::MIN);
+    // an unnormalized mantissa is normalized, and may overflow
+    assert_eq!(
+        load(r#"{ "gold": { "Number": { "m": 1e300, "e": 9223372036854775807 } } }"#),
+        Big::MAX
+    );
+    assert_eq!(
+        load(r#"{ "gold": { "Number": { "m": 25.0, "e": 3 } } }"#),
+        Big::new(2.5, 4)
+    );
+    assert_eq!(load(r#"{ "gold": "Zero" }"#), Big::Zero);
+
+    // serializing is unchanged
+    let json = serde_json::to_string(&Save { gold: b(1.5) }).unwrap();
+    assert_eq!(load(&json), b(1.5));
+
+    assert!(Big::MAX.is_normalized());
+    assert!(Big::MAX > Big::new(9.99, i64::MAX));
+    assert_eq!(-Big::MAX, Big::MIN);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn json_value() {
+    assert_eq!(b(1234.5).to_json_value(), serde_json::json!(1234.5));
+    assert_eq!(b(0).to_json_value(), serde_json::json!(0.0));
+    assert_eq!(
+        Big::new(-1.5, 3).to_json_value(),
+        serde_json::json!(-1500.0)
+    );
+    assert_eq!(
+        Big::new(1.5, 400).to_json_value(),
+        serde_json::json!("1.5e400")
+    );
+    assert_eq!(Big::NaN.to_json_value(), serde_json::json!("NaN"));
+    assert_eq!(POS_INFINITY.to_json_value(), serde_json::json!("+inf"));
+}
+
+#[test]
+fn repr() {
+    let values = [
+        Big::new(-1.5, i64::MAX),
+        Big::new(9.99, -42),
+        Big::Zero,
+        POS_INFINITY,
+        NEG_INFINITY,
+    ];
+    for value in values {
+        let repr = value.to_repr();
+        let back = Big::from_repr(repr);
+        assert_eq!(back.to_repr(), repr);
+        assert_eq!(format!("{back:?}"), format!("{value:?}"));
+    }
+
+    let nan = Big::NaN.to_repr();
+    assert_eq!(nan.tag, BigRepr::TAG_NAN);
+    assert!(Big::from_repr(nan).is_nan());
+
+    let unnormalized = BigRepr {
+        tag: BigRepr::TAG_NUMBER,
+        mantissa: 1234.5,
+        exponent: 0,
+    };
+    assert_eq!(Big::from_repr(unnormalized), b(1234.5));
+    let unknown = BigRepr {
+        tag: 42,
+        mantissa: 1.0,
+        exponent: 0,
+    };
+    assert!(Big::from_repr(unknown).is_nan());
+}
+
+#[test]
+fn from_parts_checked() {
+    assert_eq!(Big::from_parts_checked(1.0, 5), Ok(Big::new(1.0, 5)));
+    assert_eq!(Big::from_parts_checked(-9.99, -5), Ok(Big::new(-9.99, -5)));
+    assert_eq!(
+        Big::from_parts_checked(10.0, 0),
+        Err(ParseError::Mantissa("10".to_string()))
+    );
+    assert!(Big::from_parts_checked(0.5, 0).is_err());
+    assert!(Big::from_parts_checked(0.0, 0).is_err());
+    assert!(Big::from_parts_checked(f64::NAN, 0).is_err());
+    assert!(Big::from_parts_checked(f64::INFINITY, 0).is_err());
+}
+
 #[test]
 // Note: this doesn't need thorough testing because Big::new in creation
 // implicitly calls normalized
@@ -75,10 +238,136 @@ fn normalization() {
     assert_eq!(norm.m(), 1.0);
     assert_eq!(norm.e(), -3);
 
+    let norm = Big::new(0.3, 0);
+    assert_eq!(norm.m(), 3.0);
+    assert_eq!(norm.e(), -1);
+
     let norm = Big::new(0.0, 4);
     assert_eq!(norm, Big::Zero);
 }
 
+#[test]
+fn classification() {
+    let number = Big::new(-1.0, i64::MIN);
+    assert!(number.is_finite() && !number.is_infinite() && number.is_normal());
+    assert!(Big::Zero.is_finite() && !Big::Zero.is_infinite() && !Big::Zero.is_normal());
+    for inf in [POS_INFINITY, NEG_INFINITY] {
+        assert!(!inf.is_finite() && inf.is_infinite() && !inf.is_normal());
+    }
+    assert!(!Big::NaN.is_finite() && !Big::NaN.is_infinite() && !Big::NaN.is_normal());
+}
+
+#[test]
+fn parts() {
+    let number = b(-1234.5);
+    assert_eq!(number.mantissa(), Some(-1.2345));
+    assert_eq!(number.exponent(), Some(3));
+    assert_eq!(number.to_parts(), Some((-1.2345, 3)));
+    assert_eq!(
+        Big::new(25.0, i64::MIN).to_parts(),
+        Some((2.5, i64::MIN + 1))
+    );
+
+    for special in [Big::Zero, Big::NaN, POS_INFINITY, NEG_INFINITY] {
+        assert_eq!(special.mantissa(), None);
+        assert_eq!(special.exponent(), None);
+        assert_eq!(special.to_parts(), None);
+    }
+}
+
+#[test]
+fn is_copy() {
+    fn assert_copy<T: Copy>() {}
+    assert_copy::<Big>();
+    assert_copy::<InfinityKind>();
+}
+
+#[test]
+fn is_normalized() {
+    assert!(b(1234.5).is_normalized());
+    assert!(b(-1234.5).is_normalized());
+    assert!(Big::new_unnormalized(1.0, 5).is_normalized());
+    assert!(!Big::new_unnormalized(1234.5, 0).is_normalized());
+    assert!(!Big::new_unnormalized(-0.5, 0).is_normalized());
+    assert!(!Big::new_unnormalized(0.0, 0).is_normalized());
+    assert!(!Big::new_unnormalized(f64::NAN, 0).is_normalized());
+    assert!(Big::Zero.is_normalized());
+    assert!(Big::NaN.is_normalized());
+    assert!(POS_INFINITY.is_normalized());
+
+    // normalizing is idempotent
+    let mut number = Big::new_unnormalized(-1234.5, 7);
+    number.normalize();
+    assert!(number.is_normalized());
+    let once = number;
+    number.normalize();
+    assert_eq!(number, once);
+}
+
+#[test]
+fn normalize_reporting() {
+    let mut number = Big::new_unnormalized(1234.5, 0);
+    assert_eq!(number.normalize_reporting(), NormalizeOutcome::Ok);
+    assert_eq!(number, b(1234.5));
+
+    let mut number = Big::new_unnormalized(100.0, i64::MAX);
+    assert_eq!(
+        number.normalize_reporting(),
+        NormalizeOutcome::SaturatedPositive
+    );
+    assert!(number.is_pos_inf());
+
+    let mut number = Big::new_unnormalized(-12345.0, i64::MAX - 2);
+    assert_eq!(
+        number.normalize_reporting(),
+        NormalizeOutcome::SaturatedNegative
+    );
+    assert!(number.is_neg_inf());
+
+    let mut number = Big::new_unnormalized(f64::NEG_INFINITY, 0);
+    assert_eq!(
+        number.normalize_reporting(),
+        NormalizeOutcome::SaturatedNegative
+    );
+
+    let mut number = Big::new_unnormalized(0.001, i64::MIN);
+    assert_eq!(number.normalize_reporting(), NormalizeOutcome::Underflowed);
+    assert_eq!(number, Big::Zero);
+
+    // values that were already special, or had a 0.0 or NaN mantissa, are not reported
+    let mut number = Big::new_unnormalized(0.0, 5);
+    assert_eq!(number.normalize_reporting(), NormalizeOutcome::Ok);
+    assert_eq!(number, Big::Zero);
+    assert_eq!(
+        Big::new_unnormalized(f64::NAN, 5).normalize_reporting(),
+        NormalizeOutcome::Ok
+    );
+    let mut number = POS_INFINITY;
+    assert_eq!(number.normalize_reporting(), NormalizeOutcome::Ok);
+    assert_eq!(Big::Zero.normalize_reporting(), NormalizeOutcome::Ok);
+}
+
+#[test]
+fn normalize_product() {
+    assert_eq!(Big::new(-9.5, 3) * b(-9.5), Big::new(9.025, 4));
+    assert_eq!(Big::new(-9.5, 3) * b(9.5), Big::new(-9.025, 4));
+    assert!((Big::new(9.5, i64::MAX) * b(9.5)).is_pos_inf());
+    assert!((Big::new(-9.5, i64::MAX) * b(9.5)).is_neg_inf());
+
+    // many multiplies, where every product hits the single division path
+    let factors = [b(7.5), b(-3.25), b(9.99), b(2.0)];
+    let mut product = b(1);
+    let mut log = 0.0;
+    for i in 0..100_000 {
+        let factor = &factors[i % factors.len()];
+        product *= *factor;
+        log += factor.abs().log10();
+        assert!(product.is_normalized());
+    }
+    assert!((product.abs().log10() - log).abs() < 1e-6);
+    assert!(product > Big::Zero);
+}
+
 #[test]
 fn addition() {
     let mut a = b(1);
@@ -86,13 +375,53 @@ fn addition() {
     assert_eq!(a, b(2));
 
     assert_eq!(b(4) + b(-15), b(-11));
+    assert_eq!(b(4) + b(-10), b(-6));
     assert!((b(1) + Big::NaN).is_nan());
     assert_eq!(Big::Zero + b(0) + Big::Zero, Big::Zero);
     assert_eq!(b(0) + b(-0), Big::Zero);
+    assert_eq!(Big::Zero + b(9), b(9));
     assert!((b(1) + POS_INFINITY).is_pos_inf());
     assert!((Big::new(9.0, i64::MAX) + Big::new(9.0, i64::MAX)).is_pos_inf());
 }
 
+#[test]
+fn scaled_add() {
+    let a = Big::new(1.5, 20);
+    let other = Big::new(-3.0, 18);
+    assert_eq!(a.scaled_add(2.5, &other), a + other * b(2.5));
+    assert_eq!(b(100).scaled_add(0.5, &b(20)), b(110));
+    assert_eq!(b(100).scaled_add(0.0, &b(20)), b(100));
+    assert_eq!(b(100).scaled_add(-5.0, &b(20)), Big::Zero);
+    assert_eq!(
+        b(1).scaled_add(1e300, &Big::new(9.0, 10)),
+        b(1) + Big::new(9.0, 10) * b(1e300)
+    );
+
+    assert!(b(1).scaled_add(2.0, &Big::NaN).is_nan());
+    assert!(b(1).scaled_add(f64::NAN, &b(2)).is_nan());
+    assert!(b(1).scaled_add(2.0, &NEG_INFINITY).is_neg_inf());
+    assert!(b(1).scaled_add(0.0, &POS_INFINITY).is_nan());
+    assert!(POS_INFINITY.scaled_add(2.0, &b(5)).is_pos_inf());
+    assert_eq!(Big::Zero.scaled_add(2.0, &Big::Zero), Big::Zero);
+}
+
+#[test]
+fn wrap_exponent() {
+    let wrapped = Big::new(-2.5, 12345).wrap_exponent(100);
+    assert_eq!(wrapped.m(), -2.5);
+    assert_eq!(wrapped.e(), 45);
+    assert_eq!(Big::new(2.5, -1).wrap_exponent(100).e(), 99);
+    assert_eq!(Big::new(2.5, 99).wrap_exponent(100).e(), 99);
+    assert_eq!(Big::new(2.5, 100).wrap_exponent(100).e(), 0);
+    assert_eq!(Big::new(2.5, i64::MAX).wrap_exponent(10).e(), 7);
+
+    assert_eq!(Big::Zero.wrap_exponent(100), Big::Zero);
+    assert!(POS_INFINITY.wrap_exponent(100).is_pos_inf());
+    assert!(Big::NaN.wrap_exponent(100).is_nan());
+    assert!(b(5).wrap_exponent(0).is_nan());
+    assert!(b(5).wrap_exponent(-10).is_nan());
+}
+
 #[test]
 fn substraction() {
     let mut a = b(1);
@@ -103,8 +432,11 @@ fn substraction() {
     assert!((b(1) - Big::NaN).is_nan());
     assert_eq!(Big::Zero - b(0) - Big::Zero, Big::Zero);
     assert_eq!(b(0) - b(-0), Big::Zero);
+    assert_eq!(Big::Zero - b(9), b(-9));
     assert!((b(1) - POS_INFINITY).is_neg_inf());
     assert!((Big::new(-9.0, i64::MAX) - Big::new(9.0, i64::MAX)).is_neg_inf());
+    assert_eq!(b(100) - Big::new(1.0, 1000), Big::new(-1.0, 1000));
+    assert_eq!(b(100) - Big::new(-1.0, 1000), Big::new(1.0, 1000));
 }
 
 #[test]
@@ -117,6 +449,101 @@ fn multiplication() {
     assert_eq!(b(7) * b(-6), b(-42));
     assert!((POS_INFINITY * b(0)).is_nan());
     assert!((POS_INFINITY * NEG_INFINITY).is_neg_inf());
+    assert!((NEG_INFINITY * NEG_INFINITY).is_pos_inf());
+    assert!((POS_INFINITY * b(-2)).is_neg_inf());
+    assert!((b(-2) * NEG_INFINITY).is_pos_inf());
+    assert!((NEG_INFINITY * b(2)).is_neg_inf());
+}
+
+#[test]
+fn apply_bonuses() {
+    let additive = [b(0.25), b(0.75), b(2)];
+    let multiplicative = [b(2), Big::new(1.0, 100), b(0.5)];
+    assert_eq!(
+        Big::apply_bonuses(&b(3), &additive, &multiplicative),
+        Big::new(1.2, 101)
+    );
+
+    assert_eq!(Big::apply_bonuses(&b(3), &[], &[]), b(3));
+    assert_eq!(Big::apply_bonuses(&b(3), &[b(-1)], &[b(5)]), Big::Zero);
+    assert!(Big::apply_bonuses(&b(3), &[b(1)], &[Big::NaN]).is_nan());
+    assert!(Big::apply_bonuses(&b(3), &[POS_INFINITY], &[b(2)]).is_pos_inf());
+
+    // many large factors must not overflow the mantissa
+    let multiplicative = vec![b(9.9); 1000];
+    let result = Big::apply_bonuses(&b(1), &[], &multiplicative);
+    assert_eq!(result.e(), (1000.0 * 9.9_f64.log10()) as i64);
+
+    // many large bonuses must not discard the running total
+    let mut additive = vec![b(5e14); 30];
+    additive.push(b(1e15));
+    let result = Big::apply_bonuses(&b(1), &additive, &[]);
+    assert!((result - b(1.6e16)).abs() < b(100));
+}
+
+#[test]
+fn prestige_gain() {
+    let requirement = Big::new(1.0, 10);
+    let cap = Big::new(1.0, 100);
+
+    assert_eq!(
+        Big::prestige_gain(&Big::new(9.9, 9), &requirement, 0.5, &cap),
+        Big::Zero
+    );
+    assert_eq!(
+        Big::prestige_gain(&requirement, &requirement, 0.5, &cap),
+        b(1)
+    );
+    assert_eq!(
+        Big::prestige_gain(&Big::new(5.0, 10), &requirement, 1.0, &cap),
+        b(5)
+    );
+    // 2.5^2 = 6.25
+    assert_eq!(
+        Big::prestige_gain(&Big::new(2.5, 10), &requirement, 2.0, &cap),
+        b(6)
+    );
+    let gain = Big::prestige_gain(&Big::new(1.0, 1010), &requirement, 0.05, &cap);
+    assert_eq!(gain.e(), 50);
+    assert_eq!(
+        Big::prestige_gain(&Big::new(1.0, 5000), &requirement, 0.5, &cap),
+        cap
+    );
+    assert_eq!(
+        Big::prestige_gain(&POS_INFINITY, &requirement, 0.5, &cap),
+        cap
+    );
+    // the exponent of the gain does not fit into an i32
+    assert_eq!(
+        Big::prestige_gain(&b(10), &b(1), -4294967290.5, &cap),
+        Big::Zero
+    );
+    assert_eq!(
+        Big::prestige_gain(&b(10), &b(1), -4294967000.0, &cap),
+        Big::Zero
+    );
+}
+
+#[test]
+fn rescale() {
+    let (zero, ten, hundred) = (b(0), b(10), b(100));
+    assert_eq!(b(5).rescale(&zero, &ten, &zero, &hundred), b(50));
+    assert_eq!(b(15).rescale(&zero, &ten, &zero, &hundred), b(150));
+    assert_eq!(b(5).rescale(&zero, &ten, &hundred, &zero), b(50));
+    assert_eq!(b(2).rescale(&zero, &ten, &b(-10), &b(10)), b(-6));
+    assert_eq!(
+        Big::new(5.0, 500).rescale(&zero, &Big::new(1.0, 501), &zero, &ten),
+        b(5)
+    );
+
+    assert_eq!(b(5).rescale(&ten, &ten, &zero, &hundred), zero);
+    assert!(b(5)
+        .rescale(&zero, &POS_INFINITY, &zero, &hundred)
+        .is_zero());
+    assert!(POS_INFINITY
+        .rescale(&zero, &ten, &zero, &hundred)
+        .is_pos_inf());
+    assert!(Big::NaN.rescale(&zero, &ten, &zero, &hundred).is_nan());
 }
 
 #[test]
@@ -127,11 +554,83 @@ fn division() {
 
     assert_eq!(b(42) / b(6), b(7));
     assert_eq!(b(42) / b(-6), b(-7));
+    assert_eq!(b(1) / b(8), b(0.125));
     assert!((b(42) / b(0)).is_nan());
     assert!((POS_INFINITY / b(0)).is_nan());
     assert!((POS_INFINITY / NEG_INFINITY).is_nan());
 }
 
+#[test]
+fn reference_operators() {
+    // e.g. values borrowed from a collection
+    let values = [Big::new(1.5, 100), b(-4), b(7)];
+    let (a, c, seven) = (&values[0], &values[1], &values[2]);
+
+    assert_eq!(a + c, values[0] + values[1]);
+    assert_eq!(a - c, values[0] - values[1]);
+    assert_eq!(a * c, Big::new(-6.0, 100));
+    assert_eq!(a / c, Big::new(-3.75, 99));
+    assert_eq!(seven % c, b(3));
+
+    // mixed operands
+    assert_eq!(a + b(1), values[0]);
+    assert_eq!(b(2) * a, Big::new(3.0, 100));
+    assert_eq!(c - Big::new(1.0, 100), Big::new(-1.0, 100));
+    assert!((a / Big::Zero).is_nan());
+    assert!((POS_INFINITY * c).is_neg_inf());
+
+    // the operands are still usable
+    assert_eq!(values, [Big::new(1.5, 100), b(-4), b(7)]);
+}
+
+#[test]
+fn primitive_operators() {
+    assert_eq!(b(10) * 2.5, b(25));
+    assert_eq!(b(10) + 5i64, b(15));
+    assert_eq!(b(10) - 15i32, b(-5));
+    assert_eq!(b(10) / 4.0, b(2.5));
+    assert_eq!(b(10) % 4, b(10) % b(4));
+    assert_eq!(Big::new(1.0, 1000) * 2, Big::new(2.0, 1000));
+
+    let mut money = b(100);
+    money *= 1.5;
+    money += 50i64;
+    money -= 20;
+    money /= 2.0;
+    assert_eq!(money, b(90));
+
+    // special values go through the same rules as Big
+    assert!((b(10) * f64::INFINITY).is_pos_inf());
+    assert!((b(-10) * f64::INFINITY).is_neg_inf());
+    assert_eq!(b(10) / f64::INFINITY, Big::Zero);
+    assert!((b(10) + f64::NAN).is_nan());
+    assert!((b(10) / 0i32).is_nan());
+    assert!((POS_INFINITY - f64::INFINITY).is_nan());
+}
+
+#[test]
+fn floor_div_count() {
+    assert_eq!(b(100).floor_div_count(&b(30)), Some(3));
+    assert_eq!(b(90).floor_div_count(&b(30)), Some(3));
+    assert_eq!(b(7.5).floor_div_count(&b(2.5)), Some(3));
+    assert_eq!(b(29).floor_div_count(&b(30)), Some(0));
+    assert_eq!(Big::new(1.0, -50).floor_div_count(&b(30)), Some(0));
+    assert_eq!(Big::new(1.0, 20).floor_div_count(&b(1)), Some(u64::MAX));
+    assert_eq!(Big::new(1.0, 100).floor_div_count(&b(3)), Some(u64::MAX));
+    assert_eq!(
+        Big::new(1.0, i64::MAX).floor_div_count(&Big::new(1.0, i64::MIN)),
+        Some(u64::MAX)
+    );
+    assert_eq!(b(-100).floor_div_count(&b(30)), Some(0));
+    assert_eq!(Big::new(-1.0, 100).floor_div_count(&b(3)), Some(0));
+    assert_eq!(Big::Zero.floor_div_count(&b(3)), Some(0));
+
+    assert_eq!(b(100).floor_div_count(&Big::Zero), None);
+    assert_eq!(b(100).floor_div_count(&Big::NaN), None);
+    assert_eq!(b(100).floor_div_count(&POS_INFINITY), None);
+    assert_eq!(POS_INFINITY.floor_div_count(&b(3)), None);
+}
+
 #[test]
 fn logarithms() {
     assert_eq!(b(f64::consts::E).ln(), 1.0);
@@ -142,9 +641,27 @@ fn logarithms() {
     assert!(b(-10.0).log10().is_nan());
 }
 
+#[test]
+fn signed_log10() {
+    assert_eq!(Big::new(1.0, 300).signed_log10(), 300.0);
+    assert_eq!(Big::new(-1.0, 300).signed_log10(), -300.0);
+    assert_eq!(Big::new(-1.0, 5000).signed_log10(), -5000.0);
+    assert_eq!(b(10).signed_log10(), 1.0);
+    assert_eq!(b(-10).signed_log10(), -1.0);
+    assert_eq!(b(5).signed_log10(), 0.5);
+    assert_eq!(b(-0.5).signed_log10(), -0.05);
+    assert_eq!(Big::new(1.0, i64::MIN).signed_log10(), 0.0);
+    assert_eq!(Big::Zero.signed_log10(), 0.0);
+    assert_eq!(POS_INFINITY.signed_log10(), f64::INFINITY);
+    assert_eq!(NEG_INFINITY.signed_log10(), f64::NEG_INFINITY);
+    assert!(Big::NaN.signed_log10().is_nan());
+}
+
 #[test]
 fn power() {
     assert_eq!(b(16.0).powf(0.5), b(4.0));
+    assert!(b(4).powf(-0.5).is_normalized());
+    assert!(Big::new(2.0, -50).powf(1.5).is_normalized());
     assert_eq!(b(-4.0).powf(2.0), b(16.0));
     assert_eq!(b(0.25).powf(-1.0), b(4.0));
     assert_eq!(b(3454.0).powf(0.0), b(1.0));
@@ -154,6 +671,349 @@ fn power() {
     assert_eq!(Big::new(1.0, i64::MAX - 1).powf(-2.0), Big::Zero);
 }
 
+#[test]
+fn power_of_zero() {
+    assert_eq!(Big::Zero.powf(2.0), Big::Zero);
+    assert_eq!(Big::Zero.powf(0.5), Big::Zero);
+    assert_eq!(Big::Zero.powf(1e-310), Big::Zero);
+    assert_eq!(Big::Zero.powf(f64::INFINITY), Big::Zero);
+    assert!(Big::Zero.powf(-2.0).is_pos_inf());
+    assert!(Big::Zero.powf(-0.5).is_pos_inf());
+    assert!(Big::Zero.powf(-3.0).is_pos_inf());
+    assert!(Big::Zero.powf(f64::NEG_INFINITY).is_pos_inf());
+    assert!(Big::Zero.powf(0.0).is_nan());
+    assert!(Big::Zero.powf(-0.0).is_nan());
+    assert!(Big::Zero.powf(f64::NAN).is_nan());
+}
+
+#[test]
+fn square() {
+    assert_eq!(b(12).square(), b(144));
+    assert_eq!(b(-12).square(), b(144));
+    assert_eq!(b(0.5).square(), b(0.25));
+    assert_eq!(Big::new(4.0, 1000).square(), Big::new(1.6, 2001));
+    assert_eq!(Big::new(-3.0, -1000).square(), Big::new(9.0, -2000));
+    assert_eq!(Big::Zero.square(), Big::Zero);
+    assert!(Big::NaN.square().is_nan());
+    assert!(NEG_INFINITY.square().is_pos_inf());
+
+    assert!(Big::new(1.0, i64::MAX / 2 + 1).square().is_pos_inf());
+    assert!(Big::new(-5.0, i64::MAX).square().is_pos_inf());
+    assert_eq!(Big::new(5.0, i64::MIN / 2 - 1).square(), Big::Zero);
+    // the mantissa carries the exponent back into range
+    assert_eq!(
+        Big::new(5.0, i64::MIN / 2).square(),
+        Big::new(2.5, i64::MIN + 1)
+    );
+
+    let mut number = b(3);
+    number.square_mut();
+    assert_eq!(number, b(9));
+}
+
+#[test]
+fn from_ratio() {
+    let third = Big::from_ratio(1, 3);
+    assert_eq!(third.m(), 10.0 / 3.0);
+    assert_eq!(third.e(), -1);
+    assert_eq!(Big::from_ratio(-6, 3), b(-2));
+    assert_eq!(Big::from_ratio(6, -3), b(-2));
+    assert_eq!(Big::from_ratio(0, 5), Big::Zero);
+    assert!(Big::from_ratio(1, 0).is_pos_inf());
+    assert!(Big::from_ratio(-1, 0).is_neg_inf());
+    assert!(Big::from_ratio(0, 0).is_nan());
+}
+
+#[test]
+fn to_i64_saturating() {
+    assert_eq!(b(42.9).to_i64_saturating(), 42);
+    assert_eq!(b(-42.9).to_i64_saturating(), -42);
+    assert_eq!(b(0.9).to_i64_saturating(), 0);
+    assert_eq!(Big::Zero.to_i64_saturating(), 0);
+    assert_eq!(Big::NaN.to_i64_saturating(), 0);
+    assert_eq!(
+        Big::new(9.0, 18).to_i64_saturating(),
+        9_000_000_000_000_000_000
+    );
+    assert_eq!(Big::new(9.3, 18).to_i64_saturating(), i64::MAX);
+    assert_eq!(Big::new(-9.3, 18).to_i64_saturating(), i64::MIN);
+    assert_eq!(Big::new(1.0, i64::MAX).to_i64_saturating(), i64::MAX);
+    assert_eq!(Big::new(-1.0, i64::MAX).to_i64_saturating(), i64::MIN);
+    assert_eq!(POS_INFINITY.to_i64_saturating(), i64::MAX);
+    assert_eq!(NEG_INFINITY.to_i64_saturating(), i64::MIN);
+}
+
+#[test]
+fn sqrt() {
+    assert_eq!(b(144).sqrt(), b(12));
+    assert_eq!(b(1440000).sqrt(), b(1200));
+    assert_eq!(b(0.0144).sqrt(), b(0.12));
+    assert_eq!(
+        Big::new(1.0, 1001).sqrt(),
+        Big::new(3.1622776601683795, 500)
+    );
+    assert_eq!(
+        Big::new(1.0, -1001).sqrt(),
+        Big::new(3.1622776601683795, -501)
+    );
+
+    let root = Big::new(1.0, i64::MAX).sqrt();
+    assert_eq!(root, Big::new(3.1622776601683795, i64::MAX / 2));
+    assert_eq!(Big::new(1.0, i64::MIN).sqrt(), Big::new(1.0, i64::MIN / 2));
+
+    assert_eq!(Big::Zero.sqrt(), Big::Zero);
+    assert!(b(-4).sqrt().is_nan());
+    assert!(Big::NaN.sqrt().is_nan());
+    assert!(POS_INFINITY.sqrt().is_pos_inf());
+    assert!(NEG_INFINITY.sqrt().is_nan());
+
+    let mut number = b(81);
+    number.sqrt_mut();
+    assert_eq!(number, b(9));
+}
+
+#[test]
+fn root() {
+    assert_eq!(b(27).root(3.0), b(3));
+    assert_eq!(b(-27).root(3.0), b(-3));
+    assert_eq!(b(-32).root(5.0), b(-2));
+    assert!(b(-16).root(4.0).is_nan());
+    assert!(b(-16).root(2.0).is_nan());
+    assert!(b(-16).root(2.5).is_nan());
+    assert_eq!(b(16).root(4.0), b(2));
+    assert_eq!(b(144).root(2.0), b(12));
+    assert_eq!(
+        Big::new(1.0, 3001).root(3.0),
+        Big::new(2.154434690031884, 1000)
+    );
+    assert_eq!(Big::new(-8.0, -3000).root(3.0), Big::new(-2.0, -1000));
+    assert_eq!(Big::new(1.0, i64::MAX).root(7.0).e(), i64::MAX / 7);
+    assert_eq!(b(4).root(-2.0), b(0.5));
+    assert_eq!(b(-8).root(-3.0), b(-0.5));
+
+    assert_eq!(b(42).root(1.0), b(42));
+    assert_eq!(b(-42).root(1.0), b(-42));
+    assert!(b(42).root(0.0).is_nan());
+    assert!(b(42).root(f64::NAN).is_nan());
+
+    assert_eq!(Big::Zero.root(3.0), Big::Zero);
+    assert!(Big::NaN.root(3.0).is_nan());
+    assert!(POS_INFINITY.root(2.0).is_pos_inf());
+    assert!(NEG_INFINITY.root(3.0).is_neg_inf());
+    assert!(NEG_INFINITY.root(2.0).is_nan());
+    assert_eq!(POS_INFINITY.root(-2.0), Big::Zero);
+    assert!(Big::Zero.root(-2.0).is_pos_inf());
+
+    let mut number = b(1000);
+    number.root_mut(3.0);
+    assert_eq!(number, b(10));
+}
+
+#[test]
+fn recip() {
+    assert_eq!(b(4).recip(), b(0.25));
+    assert_eq!(b(-0.5).recip(), b(-2));
+    assert_eq!(b(1000).recip(), b(0.001));
+    assert_eq!(Big::new(-1.0, -50).recip(), Big::new(-1.0, 50));
+    for value in [b(3), b(-7.5), Big::new(1.2345, 678), Big::new(9.99, -4321)] {
+        let expected = b(1) / value;
+        assert!((value.recip() / expected - b(1)).abs() < Big::new(1.0, -15));
+    }
+
+    // huge numbers are near Zero, and the exponent range is not symmetric
+    assert_eq!(Big::new(2.0, 1000).recip(), Big::new(5.0, -1001));
+    assert_eq!(Big::new(2.0, i64::MIN).recip(), Big::new(5.0, i64::MAX));
+    assert!(Big::new(1.0, i64::MIN).recip().is_pos_inf());
+    assert!(Big::new(-1.0, i64::MIN).recip().is_neg_inf());
+
+    let mut number = b(8);
+    number.recip_mut();
+    assert_eq!(number, b(0.125));
+
+    assert!(Big::Zero.recip().is_pos_inf());
+    assert_eq!(POS_INFINITY.recip(), Big::Zero);
+    assert_eq!(NEG_INFINITY.recip(), Big::Zero);
+    assert!(Big::NaN.recip().is_nan());
+}
+
+#[test]
+fn cube() {
+    assert_eq!(b(-2).cube(), b(-8));
+    assert_eq!(b(3).cube(), b(27));
+    assert_eq!(b(0.5).cube(), b(0.125));
+    assert_eq!(Big::new(-3.0, 1000).cube(), Big::new(-2.7, 3001));
+    assert_eq!(Big::Zero.cube(), Big::Zero);
+    assert!(Big::NaN.cube().is_nan());
+    assert!(POS_INFINITY.cube().is_pos_inf());
+    assert!(NEG_INFINITY.cube().is_neg_inf());
+
+    assert!(Big::new(1.0, i64::MAX / 3 + 1).cube().is_pos_inf());
+    assert!(Big::new(-1.0, i64::MAX / 3 + 1).cube().is_neg_inf());
+    assert_eq!(Big::new(1.0, i64::MIN / 3 - 1).cube(), Big::Zero);
+
+    let mut number = b(-4);
+    number.cube_mut();
+    assert_eq!(number, b(-64));
+}
+
+#[test]
+fn power_negative_base() {
+    assert_eq!(b(-8).powf(3.0), b(-512));
+    assert!(b(-4).powf(0.5).is_nan());
+    assert_eq!(b(-2).powf(10.0), b(1024));
+    assert_eq!(b(-2).powf(-1.0), b(-0.5));
+    assert_eq!(b(-10).powf(2.0), b(100));
+    assert_eq!(Big::new(-1.0, 100).powf(3.0), Big::new(-1.0, 300));
+    assert!(NEG_INFINITY.powf(3.0).is_neg_inf());
+    assert!(NEG_INFINITY.powf(2.0).is_pos_inf());
+    assert_eq!(NEG_INFINITY.powf(-3.0), Big::Zero);
+    assert_eq!(POS_INFINITY.powf(-2.0), Big::Zero);
+}
+
+#[test]
+fn power_infinite() {
+    assert!(b(2).powf(f64::INFINITY).is_pos_inf());
+    assert_eq!(b(2).powf(f64::NEG_INFINITY), Big::Zero);
+    assert_eq!(b(0.5).powf(f64::INFINITY), Big::Zero);
+    assert!(b(0.5).powf(f64::NEG_INFINITY).is_pos_inf());
+    assert_eq!(b(1).powf(f64::INFINITY), b(1));
+    assert_eq!(b(1).powf(f64::NEG_INFINITY), b(1));
+    assert_eq!(b(-1).powf(f64::INFINITY), b(1));
+    assert!(b(-2).powf(f64::INFINITY).is_pos_inf());
+    assert_eq!(Big::Zero.powf(f64::INFINITY), Big::Zero);
+    assert!(Big::Zero.powf(f64::NEG_INFINITY).is_pos_inf());
+    assert!(NEG_INFINITY.powf(f64::INFINITY).is_pos_inf());
+    assert_eq!(POS_INFINITY.powf(f64::NEG_INFINITY), Big::Zero);
+    assert!(Big::NaN.powf(f64::INFINITY).is_nan());
+}
+
+#[test]
+fn power_exact() {
+    assert_eq!(b(7).powf(1.0), b(7));
+    assert_eq!(b(-7.123456789).powf(1.0), b(-7.123456789));
+    assert_eq!(Big::new(3.3, 123456789).powf(1.0), Big::new(3.3, 123456789));
+    assert!(POS_INFINITY.powf(1.0).is_pos_inf());
+    assert!(Big::NaN.powf(1.0).is_nan());
+    assert_eq!(Big::Zero.powf(1.0), Big::Zero);
+
+    assert_eq!(b(7).powf(0.0), b(1));
+    assert_eq!(b(-7).powf(0.0), b(1));
+    assert_eq!(Big::new(3.3, -123456789).powf(0.0), b(1));
+    assert_eq!(b(7).powf(-0.0), b(1));
+    assert!(Big::Zero.powf(0.0).is_nan());
+}
+
+#[test]
+fn try_sqrt() {
+    assert_eq!(b(16).try_sqrt(), Ok(b(4)));
+    assert_eq!(Big::new(1.0, 100).try_sqrt(), Ok(Big::new(1.0, 50)));
+    assert_eq!(Big::Zero.try_sqrt(), Ok(Big::Zero));
+    assert!(POS_INFINITY.try_sqrt().unwrap().is_pos_inf());
+
+    assert_eq!(b(-16).try_sqrt(), Err(MathError::Negative));
+    assert_eq!(NEG_INFINITY.try_sqrt(), Err(MathError::Negative));
+    assert_eq!(Big::NaN.try_sqrt(), Err(MathError::NaN));
+}
+
+#[test]
+fn from_log10() {
+    let mut number = Big::Zero;
+    number.set_from_log10(2.0);
+    assert_eq!(number, b(100));
+    number.set_from_log10(-2.0);
+    assert_eq!(number, b(0.01));
+    number.set_from_log10(1234.5);
+    assert_eq!(number.e(), 1234);
+    assert!((number.m() - 10f64.sqrt()).abs() < 1e-12);
+
+    number.set_from_log10(f64::INFINITY);
+    assert!(number.is_pos_inf());
+    number.set_from_log10(f64::NEG_INFINITY);
+    assert!(number.is_zero());
+    number.set_from_log10(f64::NAN);
+    assert!(number.is_nan());
+    number.set_from_log10(1e300);
+    assert!(number.is_pos_inf());
+    number.set_from_log10(-1e300);
+    assert!(number.is_zero());
+}
+
+#[test]
+fn ln_1p() {
+    let x = Big::new(1.0, -3);
+    let expected = (b(1) + x).ln();
+    assert!((x.ln_1p() - expected).abs() < 1e-12);
+    assert!((x.log10_1p() - (b(1) + x).log10()).abs() < 1e-12);
+
+    // 1 + 10^-20 is 1 at the precision of the mantissa, but ln_1p keeps it
+    let tiny = Big::new(1.0, -20);
+    assert_eq!((b(1) + tiny).ln(), 0.0);
+    assert_eq!(tiny.ln_1p(), 1e-20);
+    assert_eq!(Big::new(-1.0, -20).ln_1p(), -1e-20);
+
+    assert!((Big::new(1.0, 1000).ln_1p() - 1000.0 * f64::consts::LN_10).abs() < 1e-9);
+    assert_eq!(Big::new(1.0, i64::MIN).ln_1p(), 0.0);
+    assert_eq!(Big::Zero.ln_1p(), 0.0);
+    assert_eq!(b(-1).ln_1p(), f64::NEG_INFINITY);
+    assert!(b(-2).ln_1p().is_nan());
+    assert!(Big::NaN.ln_1p().is_nan());
+    assert_eq!(POS_INFINITY.ln_1p(), f64::INFINITY);
+    assert!(NEG_INFINITY.ln_1p().is_nan());
+}
+
+#[test]
+fn milestone_index() {
+    let indices: Vec<_> = [1.0, 99.0, 1e3, 5e5, 1e6, 1e9, 9.9e11]
+        .map(|value| b(value).milestone_index(3))
+        .into();
+    assert_eq!(
+        indices,
+        [
+            Some(0),
+            Some(0),
+            Some(1),
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(3)
+        ]
+    );
+    assert_eq!(Big::new(1.0, 300).milestone_index(3), Some(100));
+    assert_eq!(b(0.5).milestone_index(3), Some(-1));
+    assert_eq!(Big::new(1.0, -3).milestone_index(3), Some(-1));
+    assert_eq!(Big::new(1.0, -4).milestone_index(3), Some(-2));
+    assert_eq!(Big::new(1.0, i64::MAX).milestone_index(1), Some(i64::MAX));
+
+    // crossing a milestone between two frames
+    let (before, after) = (b(999), b(1001));
+    assert!(after.milestone_index(3) > before.milestone_index(3));
+
+    assert_eq!(b(-1000).milestone_index(3), None);
+    assert_eq!(b(1000).milestone_index(0), None);
+    assert_eq!(b(1000).milestone_index(-3), None);
+    assert_eq!(Big::Zero.milestone_index(3), None);
+    assert_eq!(Big::NaN.milestone_index(3), None);
+    assert_eq!(POS_INFINITY.milestone_index(3), None);
+}
+
+#[test]
+fn log_bucket() {
+    assert_eq!(b(1).log_bucket(1.0), Some(0));
+    assert_eq!(b(9.99).log_bucket(1.0), Some(0));
+    assert_eq!(b(10).log_bucket(1.0), Some(1));
+    assert_eq!(b(12345).log_bucket(1.0), Some(4));
+    assert_eq!(b(0.5).log_bucket(1.0), Some(-1));
+    assert_eq!(Big::new(5.0, 1000).log_bucket(1.0), Some(1000));
+    assert_eq!(Big::new(5.0, 1000).log_bucket(0.5), Some(2001));
+
+    assert_eq!(b(-5).log_bucket(1.0), None);
+    assert_eq!(Big::Zero.log_bucket(1.0), None);
+    assert_eq!(Big::NaN.log_bucket(1.0), None);
+    assert_eq!(POS_INFINITY.log_bucket(1.0), None);
+    assert_eq!(b(5).log_bucket(0.0), None);
+    assert_eq!(b(5).log_bucket(f64::NAN), None);
+}
+
 #[test]
 fn remainder() {
     assert_eq!(b(8) % b(3), b(2));
@@ -164,6 +1024,302 @@ fn remainder() {
     assert_eq!(b(5) % POS_INFINITY, b(5));
     assert!((POS_INFINITY % b(5)).is_nan());
     assert!((b(42) % b(0)).is_nan());
+    assert!((b(42) % Big::NaN).is_nan());
+    assert!((Big::NaN % b(42)).is_nan());
+}
+
+#[test]
+fn checked_remainder() {
+    assert_eq!(b(8).checked_rem(&b(3)), Some(b(2)));
+    assert_eq!(b(-8).checked_rem(&b(3)), Some(b(-2)));
+    assert_eq!(b(9).checked_rem(&b(3)), Some(Big::Zero));
+    assert_eq!(b(8).checked_rem(&Big::Zero), None);
+    assert_eq!(Big::Zero.checked_rem(&Big::Zero), None);
+    assert!(b(8).checked_rem(&Big::NaN).unwrap().is_nan());
+    assert!(POS_INFINITY.checked_rem(&b(3)).unwrap().is_nan());
+}
+
+#[test]
+fn remainder_zero() {
+    assert!((Big::new(1.2345, 1234) % b(5)).is_zero());
+    assert!((Big::new(-1.2345, 1234) % b(5)).is_zero());
+    assert!((b(9) % b(3)).is_zero());
+    assert!((b(-9) % b(3)).is_zero());
+    assert!((Big::new(6.0, -500) % Big::new(2.0, -500)).is_zero());
+
+    let mut rem = b(9);
+    rem %= b(3);
+    assert!(rem.is_zero());
+
+    // a tiny remainder must not collapse to Zero
+    let rem = Big::new(3.0, -20) % Big::new(2.0, -20);
+    assert_eq!(rem, Big::new(1.0, -20));
+    let rem = Big::new(1.0, -300) % b(5);
+    assert_eq!(rem, Big::new(1.0, -300));
+}
+
+#[test]
+fn interp_table() {
+    let table = [(0.0, b(10)), (10.0, b(1000)), (20.0, Big::new(1.0, 103))];
+    assert_eq!(Big::interp_table(0.0, &table), b(10));
+    assert_eq!(Big::interp_table(5.0, &table), b(100));
+    assert_eq!(Big::interp_table(10.0, &table), b(1000));
+    assert_eq!(Big::interp_table(12.5, &table), Big::new(1.0, 28));
+    assert_eq!(Big::interp_table(20.0, &table), Big::new(1.0, 103));
+
+    // clamped outside of the table
+    assert_eq!(Big::interp_table(-5.0, &table), b(10));
+    assert_eq!(Big::interp_table(f64::INFINITY, &table), Big::new(1.0, 103));
+
+    assert_eq!(Big::interp_table(3.0, &table[..1]), b(10));
+    assert!(Big::interp_table(3.0, &[]).is_nan());
+    assert!(Big::interp_table(f64::NAN, &table).is_nan());
+    assert!(Big::interp_table(5.0, &[(0.0, Big::Zero), (10.0, b(1))]).is_nan());
+}
+
+#[test]
+fn from_log_slider() {
+    let (min, max) = (b(1), b(10000));
+    assert_eq!(Big::from_log_slider(0.5, &min, &max), b(100));
+    assert_eq!(Big::from_log_slider(0.0, &min, &max), min);
+    assert_eq!(Big::from_log_slider(1.0, &min, &max), max);
+    assert_eq!(Big::from_log_slider(0.25, &min, &max), b(10));
+    assert_eq!(Big::from_log_slider(-1.0, &min, &max), min);
+    assert_eq!(Big::from_log_slider(2.0, &min, &max), max);
+
+    let huge = Big::from_log_slider(0.5, &Big::new(1.0, 100), &Big::new(1.0, 300));
+    assert_eq!(huge, Big::new(1.0, 200));
+    // reversed bounds work as well
+    assert_eq!(Big::from_log_slider(0.75, &max, &min), b(10));
+
+    assert!(Big::from_log_slider(0.5, &Big::Zero, &max).is_nan());
+    assert!(Big::from_log_slider(0.5, &b(-1), &max).is_nan());
+    assert!(Big::from_log_slider(0.5, &min, &Big::NaN).is_nan());
+    assert!(Big::from_log_slider(f64::NAN, &min, &max).is_nan());
+}
+
+#[test]
+fn ease_log() {
+    let start = b(10);
+    let end = Big::new(1.0, 101);
+    assert_eq!(
+        Big::ease_log(&start, &end, 0.5, Easing::Smoothstep),
+        Big::new(1.0, 51)
+    );
+    assert_eq!(
+        Big::ease_log(&start, &end, 0.5, Easing::Linear),
+        Big::new(1.0, 51)
+    );
+    assert_eq!(
+        Big::ease_log(&start, &end, 0.5, Easing::QuadIn),
+        Big::new(1.0, 26)
+    );
+    assert_eq!(
+        Big::ease_log(&start, &end, 0.5, Easing::QuadOut),
+        Big::new(1.0, 76)
+    );
+    let eased = Big::ease_log(&start, &end, 0.25, Easing::Smoothstep);
+    assert!((eased.log10() - 16.625).abs() < 1e-12);
+
+    assert_eq!(Big::ease_log(&start, &end, 0.0, Easing::QuadIn), start);
+    assert_eq!(Big::ease_log(&start, &end, 1.0, Easing::QuadOut), end);
+    assert_eq!(Big::ease_log(&start, &end, -1.0, Easing::Linear), start);
+    assert_eq!(Big::ease_log(&start, &end, 2.0, Easing::Linear), end);
+    assert!(Big::ease_log(&start, &end, f64::NAN, Easing::Linear).is_nan());
+    assert!(Big::ease_log(&Big::Zero, &end, 0.5, Easing::Linear).is_nan());
+
+    assert_eq!(Easing::Smoothstep.apply(0.5), 0.5);
+    assert_eq!(Easing::Smoothstep.apply(0.25), 0.15625);
+    assert_eq!(Easing::QuadOut.apply(0.25), 0.4375);
+}
+
+#[test]
+fn add_capped() {
+    let cap = b(100);
+    assert_eq!(b(90).add_capped(&b(20), &cap), b(100));
+    assert_eq!(b(80).add_capped(&b(20), &cap), b(100));
+    assert_eq!(b(50).add_capped(&b(20), &cap), b(70));
+    assert_eq!(b(150).add_capped(&b(-20), &cap), b(100));
+
+    let huge = Big::new(9.0, i64::MAX);
+    assert_eq!(huge.add_capped(&huge, &cap), b(100));
+    assert_eq!(POS_INFINITY.add_capped(&b(1), &cap), b(100));
+    assert_eq!(b(1).add_capped(&b(1), &POS_INFINITY), b(2));
+    assert!(Big::NaN.add_capped(&b(1), &cap).is_nan());
+    assert!(b(1).add_capped(&b(1), &Big::NaN).is_nan());
+}
+
+#[test]
+fn spend() {
+    assert_eq!(b(100).spend(&b(30)), b(70));
+    assert_eq!(b(100).spend(&b(100)), Big::Zero);
+    assert_eq!(b(100).spend(&b(130)), Big::Zero);
+    assert_eq!(b(100).spend(&Big::new(1.0, 1000)), Big::Zero);
+    assert_eq!(b(100).spend(&POS_INFINITY), Big::Zero);
+    assert!(POS_INFINITY.spend(&b(100)).is_pos_inf());
+    assert!(POS_INFINITY.spend(&POS_INFINITY).is_nan());
+    assert!(Big::NaN.spend(&b(1)).is_nan());
+    assert!(b(1).spend(&Big::NaN).is_nan());
+}
+
+#[test]
+fn try_spend() {
+    assert_eq!(b(100).try_spend(&b(30)), Some(b(70)));
+    assert_eq!(b(100).try_spend(&b(100)), Some(Big::Zero));
+    assert_eq!(b(100).try_spend(&b(130)), None);
+    assert_eq!(b(100).try_spend(&Big::new(1.0, 1000)), None);
+    assert_eq!(
+        Big::new(1.0, 1000).try_spend(&b(100)),
+        Some(Big::new(1.0, 1000))
+    );
+    assert!(POS_INFINITY
+        .try_spend(&b(100))
+        .is_some_and(|rest| rest.is_pos_inf()));
+    assert_eq!(b(100).try_spend(&Big::NaN), None);
+    assert_eq!(Big::NaN.try_spend(&b(1)), None);
+}
+
+#[test]
+fn rounding() {
+    assert_eq!(b(2.7).floor(), b(2));
+    assert_eq!(b(-2.1).floor(), b(-3));
+    assert_eq!(b(-2.1).ceil(), b(-2));
+    assert_eq!(b(2.1).ceil(), b(3));
+    assert_eq!(b(2.5).round(), b(3));
+    assert_eq!(b(-2.5).round(), b(-3));
+    assert_eq!(b(-2.7).trunc(), b(-2));
+    assert_eq!(b(123456.789).floor(), b(123456));
+    assert_eq!(b(11).ceil(), b(11));
+
+    // within 0..1, the result may be Zero
+    assert_eq!(b(0.4).floor(), Big::Zero);
+    assert_eq!(b(-0.4).ceil(), Big::Zero);
+    assert_eq!(b(0.4).ceil(), b(1));
+    assert_eq!(Big::new(-1.0, -500).floor(), b(-1));
+    assert_eq!(Big::new(1.0, i64::MIN).trunc(), Big::Zero);
+
+    // huge numbers are already integers
+    let huge = Big::new(1.2345, 500);
+    assert_eq!(huge.floor(), huge);
+    assert_eq!(huge.ceil(), huge);
+    assert_eq!(Big::new(-1.2345, 15).round(), Big::new(-1.2345, 15));
+
+    let mut number = b(7.5);
+    number.floor_mut();
+    assert_eq!(number, b(7));
+
+    assert_eq!(Big::Zero.round(), Big::Zero);
+    assert!(Big::NaN.floor().is_nan());
+    assert!(POS_INFINITY.ceil().is_pos_inf());
+    assert!(NEG_INFINITY.trunc().is_neg_inf());
+}
+
+#[test]
+fn fract() {
+    assert_eq!(b(2.25).fract(), b(0.25));
+    assert_eq!(b(-2.25).fract(), b(-0.25));
+    assert_eq!(b(0.75).fract(), b(0.75));
+    assert_eq!(b(42).fract(), Big::Zero);
+    assert_eq!(Big::new(1.5, -400).fract(), Big::new(1.5, -400));
+    assert_eq!(Big::new(1.2345, 500).fract(), Big::Zero);
+    assert_eq!(Big::new(-1.2345, 15).fract(), Big::Zero);
+
+    assert_eq!(Big::Zero.fract(), Big::Zero);
+    assert!(Big::NaN.fract().is_nan());
+    assert!(POS_INFINITY.fract().is_nan());
+    assert!(NEG_INFINITY.fract().is_nan());
+}
+
+#[test]
+fn is_integer() {
+    assert!(b(5).is_integer());
+    assert!(b(-120).is_integer());
+    assert!(!b(5.5).is_integer());
+    assert!(!b(-0.25).is_integer());
+    assert!(!Big::new(1.0, -500).is_integer());
+    assert!(Big::new(1.2345, 500).is_integer());
+    assert!(Big::new(-1.2345, 15).is_integer());
+    assert!(Big::Zero.is_integer());
+    assert!(!Big::NaN.is_integer());
+    assert!(!POS_INFINITY.is_integer());
+    assert!(!NEG_INFINITY.is_integer());
+}
+
+#[test]
+fn with_sign() {
+    assert_eq!(b(42).with_sign(true), b(42));
+    assert_eq!(b(42).with_sign(false), b(-42));
+    assert_eq!(b(-42).with_sign(true), b(42));
+    assert_eq!(b(-42).with_sign(false), b(-42));
+    assert_eq!(
+        Big::new(-1.5, i64::MAX).with_sign(true),
+        Big::new(1.5, i64::MAX)
+    );
+    assert!(NEG_INFINITY.with_sign(true).is_pos_inf());
+    assert!(POS_INFINITY.with_sign(false).is_neg_inf());
+    assert_eq!(Big::Zero.with_sign(false), Big::Zero);
+    assert!(Big::NaN.with_sign(true).is_nan());
+}
+
+#[test]
+fn sign() {
+    let negative = Big::new(-1.0, i64::MAX);
+    assert!(negative.is_sign_negative() && !negative.is_sign_positive());
+    let positive = Big::new(1.0, i64::MIN);
+    assert!(positive.is_sign_positive() && !positive.is_sign_negative());
+    assert!(POS_INFINITY.is_sign_positive() && !POS_INFINITY.is_sign_negative());
+    assert!(NEG_INFINITY.is_sign_negative() && !NEG_INFINITY.is_sign_positive());
+    assert!(Big::Zero.is_sign_positive() && !Big::Zero.is_sign_negative());
+    assert!(!Big::NaN.is_sign_positive() && !Big::NaN.is_sign_negative());
+}
+
+#[test]
+fn signum() {
+    assert_eq!(b(42).signum(), b(1));
+    assert_eq!(b(-0.5).signum(), b(-1));
+    assert_eq!(Big::new(-1.0, i64::MAX).signum(), b(-1));
+    assert_eq!(Big::new(1.0, i64::MIN).signum(), b(1));
+    assert_eq!(POS_INFINITY.signum(), b(1));
+    assert_eq!(NEG_INFINITY.signum(), b(-1));
+    assert_eq!(Big::Zero.signum(), Big::Zero);
+    assert!(Big::NaN.signum().is_nan());
+}
+
+#[test]
+fn sign_and_magnitude() {
+    assert_eq!(b(42).sign_and_magnitude(), (1, b(42)));
+    assert_eq!(b(-42).sign_and_magnitude(), (-1, b(42)));
+    assert_eq!(
+        Big::new(-1.5, i64::MIN).sign_and_magnitude(),
+        (-1, Big::new(1.5, i64::MIN))
+    );
+    assert_eq!(Big::Zero.sign_and_magnitude(), (0, Big::Zero));
+
+    let (sign, magnitude) = NEG_INFINITY.sign_and_magnitude();
+    assert_eq!(sign, -1);
+    assert!(magnitude.is_pos_inf());
+    let (sign, magnitude) = POS_INFINITY.sign_and_magnitude();
+    assert_eq!(sign, 1);
+    assert!(magnitude.is_pos_inf());
+    let (sign, magnitude) = Big::NaN.sign_and_magnitude();
+    assert_eq!(sign, 0);
+    assert!(magnitude.is_nan());
+}
+
+#[test]
+fn map_mantissa() {
+    let double = |m: f64| m * 2.0;
+    assert_eq!(b(4).map_mantissa(double), b(8));
+    assert_eq!(b(6).map_mantissa(double), b(12));
+    assert_eq!(b(-6).map_mantissa(double), b(-12));
+    assert!(Big::new(6.0, i64::MAX).map_mantissa(double).is_pos_inf());
+    assert_eq!(b(1.25).map_mantissa(f64::floor), b(1));
+    assert_eq!(b(5).map_mantissa(|_| 0.0), Big::Zero);
+    assert!(b(5).map_mantissa(|_| f64::NAN).is_nan());
+
+    assert_eq!(Big::Zero.map_mantissa(double), Big::Zero);
+    assert!(Big::NaN.map_mantissa(double).is_nan());
+    assert!(NEG_INFINITY.map_mantissa(double).is_neg_inf());
 }
 
 #[test]
@@ -174,6 +1330,35 @@ fn negation() {
     assert!((-NEG_INFINITY).is_pos_inf());
 }
 
+#[test]
+fn display() {
+    assert_eq!(b(5).to_string(), "5e0");
+    assert_eq!(b(-1234.5).to_string(), "-1.2345e3");
+    assert_eq!(b(0).to_string(), "0");
+    assert_eq!(POS_INFINITY.to_string(), "+inf");
+    assert_eq!(NEG_INFINITY.to_string(), "-inf");
+    assert_eq!(Big::NaN.to_string(), "NaN");
+
+    assert_eq!(format!("{:+}", b(5)), "+5e0");
+    assert_eq!(format!("{:+}", b(-5)), "-5e0");
+    assert_eq!(format!("{:+}", b(0)), "+0");
+    assert_eq!(format!("{:+}", POS_INFINITY), "+inf");
+    assert_eq!(format!("{:+}", NEG_INFINITY), "-inf");
+    assert_eq!(format!("{:+}", Big::NaN), "NaN");
+
+    assert_eq!(format!("{:+.2}", b(1234.5)), "+1.23e3");
+    assert_eq!(format!("{:.2}", Big::new(9.999, 5)), "1.00e6");
+    assert_eq!(format!("{:+.2}", Big::new(-9.999, 5)), "-1.00e6");
+    assert_eq!(format!("{:.2}", Big::Zero), "0.00");
+    assert_eq!(format!("{:+.1}", Big::Zero), "+0.0");
+    assert_eq!(format!("{:>6}", Big::NaN), "   NaN");
+    assert_eq!(format!("{:*<5}", Big::NaN), "NaN**");
+    assert_eq!(format!("{:.2}", Big::NaN), "NaN");
+    assert_eq!(format!("{:>6.2}", Big::NaN), "   NaN");
+    assert_eq!(format!("{:>8}", b(-5)), "    -5e0");
+    assert_eq!(format!("{:+08.1}", b(5)), "+005.0e0");
+}
+
 #[test]
 fn comparison() {
     assert!(b(11) > b(9));
@@ -181,10 +1366,569 @@ fn comparison() {
     assert!(POS_INFINITY > Big::new(9.9, i64::MAX));
     assert!(NEG_INFINITY < Big::new(9.9, i64::MAX));
     assert!(NEG_INFINITY < POS_INFINITY);
+    assert!(Big::new(1.0, 20) > b(1));
+    assert!(Big::new(-1.0, 20) < b(1));
+    assert!(b(1) < Big::new(1.0, 20));
+    assert!(b(1) > Big::new(-1.0, 20));
     assert!(POS_INFINITY != POS_INFINITY);
     assert!(Big::NaN != Big::NaN);
 }
 
+#[test]
+fn sort_key() {
+    let mut numbers = [
+        b(5),
+        POS_INFINITY,
+        Big::new(-1.0, 400),
+        b(0),
+        Big::new(3.0, 400),
+        NEG_INFINITY,
+        b(-7),
+        b(2),
+    ];
+    numbers.sort_by(|a, b| a.sort_key().total_cmp(&b.sort_key()));
+
+    assert!(numbers[0].is_neg_inf());
+    assert_eq!(numbers[1], Big::new(-1.0, 400));
+    assert_eq!(numbers[2], b(-7));
+    assert_eq!(numbers[3], b(0));
+    assert_eq!(numbers[4], b(2));
+    assert_eq!(numbers[5], b(5));
+    assert_eq!(numbers[6], Big::new(3.0, 400));
+    assert!(numbers[7].is_pos_inf());
+
+    // within one sign, small magnitudes are ordered as well
+    assert!(b(0.001).sort_key() < b(0.01).sort_key());
+    assert!(b(-0.01).sort_key() < b(-0.001).sort_key());
+    assert!(Big::NaN.sort_key().is_nan());
+
+    // log10(1) is 0, so 1 ties with Zero
+    assert_eq!(b(1).sort_key(), Big::Zero.sort_key());
+}
+
+#[test]
+fn is_approx_one_and_zero() {
+    assert!(b(1).is_approx_one(0.0));
+    assert!(b(1.0009).is_approx_one(1e-3));
+    assert!(b(0.9991).is_approx_one(1e-3));
+    assert!(!b(1.0011).is_approx_one(1e-3));
+    assert!(!b(0.9989).is_approx_one(1e-3));
+    assert!(!b(-1).is_approx_one(1e-3));
+    assert!(!Big::Zero.is_approx_one(1e-3));
+    assert!(!Big::NaN.is_approx_one(1e-3));
+    assert!(!POS_INFINITY.is_approx_one(1e-3));
+
+    assert!(Big::Zero.is_approx_zero(0.0));
+    assert!(b(0.0009).is_approx_zero(1e-3));
+    assert!(b(-0.0009).is_approx_zero(1e-3));
+    assert!(!b(0.0011).is_approx_zero(1e-3));
+    assert!(!b(-0.0011).is_approx_zero(1e-3));
+    assert!(Big::new(1.0, -1000).is_approx_zero(1e-300));
+    assert!(!Big::NaN.is_approx_zero(1e-3));
+    assert!(!NEG_INFINITY.is_approx_zero(1e-3));
+    assert!(!b(0.5).is_approx_zero(f64::NAN));
+}
+
+#[test]
+fn is_zero_within() {
+    let tiny = Big::new(-3.0, -400);
+    assert!(tiny.is_zero_within(&Big::new(1.0, -300)));
+    assert!(tiny.is_zero_within(&Big::new(3.0, -400)));
+    assert!(!tiny.is_zero_within(&Big::new(2.0, -400)));
+    assert!(!tiny.is_zero_within(&Big::Zero));
+    assert!(!b(1).is_zero_within(&b(0.5)));
+    assert!(tiny.is_zero_within(&POS_INFINITY));
+
+    assert!(Big::Zero.is_zero_within(&Big::Zero));
+    assert!(!Big::Zero.is_zero_within(&b(-1)));
+    assert!(!Big::Zero.is_zero_within(&Big::NaN));
+    assert!(!Big::NaN.is_zero_within(&b(1)));
+    assert!(!POS_INFINITY.is_zero_within(&POS_INFINITY));
+    assert!(!NEG_INFINITY.is_zero_within(&b(1)));
+}
+
+#[test]
+fn total_cmp() {
+    let mut numbers = [
+        Big::NaN,
+        b(5),
+        POS_INFINITY,
+        Big::Zero,
+        Big::new(-1.0, 100),
+        NEG_INFINITY,
+        b(-0.5),
+        Big::new(1.0, 100),
+    ];
+    numbers.sort_by(Big::total_cmp);
+    assert!(numbers[0].is_neg_inf());
+    assert_eq!(
+        numbers[1..6],
+        [
+            Big::new(-1.0, 100),
+            b(-0.5),
+            Big::Zero,
+            b(5),
+            Big::new(1.0, 100)
+        ]
+    );
+    assert!(numbers[6].is_pos_inf());
+    assert!(numbers[7].is_nan());
+
+    assert_eq!(POS_INFINITY.total_cmp(&POS_INFINITY), Ordering::Equal);
+    assert_eq!(NEG_INFINITY.total_cmp(&NEG_INFINITY), Ordering::Equal);
+    assert_eq!(Big::NaN.total_cmp(&Big::NaN), Ordering::Equal);
+    assert_eq!(Big::NaN.total_cmp(&POS_INFINITY), Ordering::Greater);
+
+    let mut leaderboard: Vec<TotalOrd> = [b(3), Big::NaN, POS_INFINITY, b(-7), POS_INFINITY]
+        .into_iter()
+        .map(TotalOrd)
+        .collect();
+    leaderboard.sort();
+    assert_eq!(leaderboard[0], TotalOrd(b(-7)));
+    assert_eq!(leaderboard[2], leaderboard[3]);
+    assert!(leaderboard[4].0.is_nan());
+    assert_eq!(leaderboard.iter().max(), Some(&TotalOrd(Big::NaN)));
+}
+
+#[test]
+fn exponent_cmp() {
+    assert_eq!(b(2).exponent_cmp(&b(9)), Some(Ordering::Equal));
+    assert_eq!(b(9).exponent_cmp(&b(10)), Some(Ordering::Less));
+    assert_eq!(b(1000).exponent_cmp(&b(-999)), Some(Ordering::Greater));
+    assert_eq!(
+        Big::new(9.9, 500).exponent_cmp(&Big::new(1.0, 500)),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(b(5).exponent_cmp(&Big::Zero), Some(Ordering::Greater));
+    assert_eq!(b(5).exponent_cmp(&POS_INFINITY), Some(Ordering::Less));
+    assert_eq!(NEG_INFINITY.exponent_cmp(&b(5)), Some(Ordering::Less));
+    assert_eq!(b(5).exponent_cmp(&Big::NaN), None);
+}
+
+#[test]
+fn min_max_clamp() {
+    assert_eq!(b(3).min(b(5)), b(3));
+    assert_eq!(b(5).max(b(3)), b(5));
+    assert_eq!(Big::new(-1.0, 100).min(b(-5)), Big::new(-1.0, 100));
+    assert_eq!(Big::Zero.max(b(-5)), Big::Zero);
+    assert!(b(1).max(POS_INFINITY).is_pos_inf());
+    assert!(b(1).min(NEG_INFINITY).is_neg_inf());
+    assert!(b(3).min(Big::NaN).is_nan());
+    assert!(Big::NaN.max(b(3)).is_nan());
+
+    let (min, max) = (Big::Zero, b(100));
+    assert_eq!(b(150).clamp(min, max), b(100));
+    assert_eq!(b(-5).clamp(min, max), Big::Zero);
+    assert_eq!(b(42).clamp(min, max), b(42));
+    assert_eq!(POS_INFINITY.clamp(min, max), b(100));
+    assert!(b(42).clamp(Big::NaN, max).is_nan());
+    assert!(b(42).clamp(min, Big::NaN).is_nan());
+    assert!(Big::NaN.clamp(min, max).is_nan());
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn clamp_inverted_bounds() {
+    b(42).clamp(b(100), Big::Zero);
+}
+
+#[test]
+fn clamp_checked() {
+    let (min, max) = (b(0), b(100));
+    assert_eq!(b(50).clamp_checked(&min, &max), (b(50), false));
+    assert_eq!(b(100).clamp_checked(&min, &max), (b(100), false));
+    assert_eq!(b(150).clamp_checked(&min, &max), (b(100), true));
+    assert_eq!(b(-5).clamp_checked(&min, &max), (b(0), true));
+    assert_eq!(POS_INFINITY.clamp_checked(&min, &max), (b(100), true));
+
+    let (value, clamped) = Big::NaN.clamp_checked(&min, &max);
+    assert!(value.is_nan());
+    assert!(!clamped);
+    assert_eq!(b(150).clamp_checked(&min, &Big::NaN), (b(150), false));
+}
+
+#[test]
+fn is_effectively_zero() {
+    let leftover = b(1e20) - b(99999999999999990000.0);
+    assert!(leftover.is_effectively_zero(&b(1e20), 12.0));
+    assert!(Big::new(1.0, -14).is_effectively_zero(&b(1), 12.0));
+    assert!(Big::new(-1.0, -14).is_effectively_zero(&b(-1), 12.0));
+    assert!(!Big::new(1.0, -14).is_effectively_zero(&b(1), 14.0));
+    assert!(!b(1).is_effectively_zero(&b(1), 0.0));
+    assert!(b(1).is_effectively_zero(&b(100), 1.5));
+
+    assert!(Big::Zero.is_effectively_zero(&Big::Zero, 12.0));
+    assert!(b(1).is_effectively_zero(&POS_INFINITY, 12.0));
+    assert!(!b(1).is_effectively_zero(&Big::Zero, 12.0));
+    assert!(!POS_INFINITY.is_effectively_zero(&b(1), 12.0));
+    assert!(!Big::NaN.is_effectively_zero(&b(1), 12.0));
+    assert!(!b(1).is_effectively_zero(&Big::NaN, 12.0));
+}
+
+#[test]
+fn sum() {
+    let values = vec![Big::new(1.0, 3); 1000];
+    assert_eq!(values.iter().sum::<Big>(), Big::new(1.0, 6));
+    assert_eq!(values.into_iter().sum::<Big>(), Big::new(1.0, 6));
+
+    let values = [b(1), Big::NaN, POS_INFINITY];
+    assert!(values.iter().sum::<Big>().is_nan());
+
+    // the running total is kept at the largest exponent, so nothing gets lost
+    let values = [b(1), Big::new(9.0, 14), Big::new(1.0, 15)];
+    assert_eq!(values.iter().sum::<Big>(), b(1_900_000_000_000_001_i64));
+    let values = [b(5), Big::new(-2.5, 3), b(-3)];
+    assert_eq!(values.iter().sum::<Big>(), b(-2498));
+
+    assert_eq!(std::iter::empty::<Big>().sum::<Big>(), Big::Zero);
+    assert!([b(1), NEG_INFINITY].iter().sum::<Big>().is_neg_inf());
+    assert!([POS_INFINITY, NEG_INFINITY].iter().sum::<Big>().is_nan());
+    assert_eq!(
+        [Big::new(1.0, i64::MIN), Big::new(1.0, i64::MAX)]
+            .iter()
+            .sum::<Big>(),
+        Big::new(1.0, i64::MAX)
+    );
+}
+
+#[test]
+fn product() {
+    assert_eq!(std::iter::empty::<Big>().product::<Big>(), b(1));
+    assert_eq!([b(2), b(-3), b(7)].iter().product::<Big>(), b(-42));
+    assert_eq!(vec![b(2); 10].into_iter().product::<Big>(), b(1024));
+
+    // the mantissa is shifted into the exponent before it overflows
+    let values = vec![b(9); 1000];
+    let product: Big = values.iter().product();
+    assert!((product.log10() - 1000.0 * 9f64.log10()).abs() < 1e-9);
+
+    // the exponent does not saturate in between
+    let values = [
+        Big::new(1.0, i64::MAX),
+        Big::new(1.0, i64::MAX),
+        Big::new(1.0, i64::MIN + 1),
+        Big::new(2.0, i64::MIN + 1),
+    ];
+    assert_eq!(values.iter().product::<Big>(), b(2));
+    assert!([Big::new(1.0, i64::MAX); 2]
+        .iter()
+        .product::<Big>()
+        .is_pos_inf());
+    assert_eq!(
+        [Big::new(1.0, i64::MIN); 2].iter().product::<Big>(),
+        Big::Zero
+    );
+
+    assert_eq!([b(2), Big::Zero, b(3)].iter().product::<Big>(), Big::Zero);
+    assert!([b(2), POS_INFINITY, b(3)]
+        .iter()
+        .product::<Big>()
+        .is_pos_inf());
+    assert!([b(-2), POS_INFINITY, b(3)]
+        .iter()
+        .product::<Big>()
+        .is_neg_inf());
+    assert!([b(-2), NEG_INFINITY].iter().product::<Big>().is_pos_inf());
+    assert!([b(2), Big::Zero, POS_INFINITY]
+        .iter()
+        .product::<Big>()
+        .is_nan());
+    assert!([b(2), Big::NaN].iter().product::<Big>().is_nan());
+}
+
+#[test]
+fn reduction() {
+    let values = [b(4), b(-15), Big::new(2.0, 3)];
+    assert_eq!(reduce(&values, |acc, value| acc + *value), Some(b(1989)));
+
+    let max = reduce(
+        &values,
+        |acc, value| {
+            if value > &acc {
+                *value
+            } else {
+                acc
+            }
+        },
+    );
+    assert_eq!(max, Some(b(2000)));
+
+    assert_eq!(reduce(&[b(42)], |acc, value| acc * *value), Some(b(42)));
+    assert_eq!(reduce(&[], |acc, value| acc + *value), None);
+}
+
+#[test]
+fn scale_by_pow10() {
+    assert_eq!(b(42).scale_by_pow10(3), b(42000));
+    assert_eq!(b(-42).scale_by_pow10(-3), b(-0.042));
+    assert_eq!(Big::Zero.scale_by_pow10(3), Big::Zero);
+    assert!(Big::NaN.scale_by_pow10(3).is_nan());
+    assert!(Big::new(1.0, i64::MAX).scale_by_pow10(1).is_pos_inf());
+    assert!(Big::new(-1.0, 1).scale_by_pow10(i64::MAX).is_neg_inf());
+    assert_eq!(Big::new(1.0, i64::MIN).scale_by_pow10(-1), Big::Zero);
+    assert_eq!(
+        Big::new(1.0, i64::MAX).scale_by_pow10(i64::MIN),
+        Big::new(1.0, -1)
+    );
+}
+
+#[test]
+fn checked_scale_by_pow10() {
+    assert_eq!(b(42).checked_scale_by_pow10(3), Some(b(42000)));
+    assert_eq!(b(-42).checked_scale_by_pow10(-3), Some(b(-0.042)));
+    assert_eq!(
+        Big::new(1.0, i64::MAX - 1).checked_scale_by_pow10(1),
+        Some(Big::new(1.0, i64::MAX))
+    );
+    assert_eq!(Big::new(1.0, i64::MAX).checked_scale_by_pow10(1), None);
+    assert_eq!(Big::new(-1.0, 1).checked_scale_by_pow10(i64::MAX), None);
+    assert_eq!(Big::new(1.0, i64::MIN).checked_scale_by_pow10(-1), None);
+    assert_eq!(
+        Big::new(1.0, i64::MIN + 1).checked_scale_by_pow10(-1),
+        Some(Big::MIN_POSITIVE)
+    );
+    assert_eq!(
+        Big::new(1.0, i64::MAX).checked_scale_by_pow10(i64::MIN),
+        Some(Big::new(1.0, -1))
+    );
+
+    assert_eq!(Big::Zero.checked_scale_by_pow10(i64::MAX), Some(Big::Zero));
+    assert!(Big::NaN.checked_scale_by_pow10(3).unwrap().is_nan());
+    assert!(POS_INFINITY.checked_scale_by_pow10(3).unwrap().is_pos_inf());
+}
+
+#[test]
+fn extreme_exponents() {
+    let values = [
+        Big::new(1.5, i64::MAX),
+        Big::new(-9.5, i64::MAX),
+        Big::new(1.5, i64::MIN),
+        Big::new(-9.5, i64::MIN),
+        Big::new(1.5, i64::MIN + 1),
+        b(1),
+        b(-0.5),
+        Big::Zero,
+        Big::NaN,
+        POS_INFINITY,
+        NEG_INFINITY,
+    ];
+
+    for a in &values {
+        let _ = -*a;
+        let _ = a.abs();
+        let _ = a.is_normalized();
+        let _ = a.log10();
+        let _ = a.ln();
+        let _ = a.log(2.0);
+        for power in [-2.0, -0.5, 0.0, 0.5, 1.0, 2.0, 1e300, f64::INFINITY] {
+            let _ = a.powf(power);
+        }
+        for n in [i64::MIN, -1, 0, 1, i64::MAX] {
+            let _ = a.scale_by_pow10(n);
+        }
+        let _ = a.wrap_exponent(i64::MAX);
+        let _ = a.sort_key();
+        let _ = a.to_string();
+        let _ = a.to_exponential(2);
+        let _ = a.to_exponential_aligned(2);
+        let mut log = *a;
+        log.set_from_log10(a.log10());
+
+        for other in &values {
+            let _ = *a + *other;
+            let _ = *a - *other;
+            let _ = *a * *other;
+            let _ = *a / *other;
+            let _ = *a % *other;
+            let _ = a.partial_cmp(other);
+            let _ = a == other;
+            let _ = a.scaled_add(2.0, other);
+        }
+    }
+
+    assert!((Big::new(5.0, i64::MAX) * Big::new(5.0, i64::MAX)).is_pos_inf());
+    assert!((Big::new(-5.0, i64::MAX) * Big::new(5.0, 1)).is_neg_inf());
+    assert_eq!(Big::new(5.0, i64::MIN) * Big::new(1.0, -1), Big::Zero);
+    assert!((Big::new(5.0, i64::MAX) / Big::new(1.0, -1)).is_pos_inf());
+    assert_eq!(Big::new(5.0, i64::MIN) / Big::new(1.0, i64::MAX), Big::Zero);
+    assert_eq!(
+        Big::new(5.0, i64::MAX) + Big::new(5.0, i64::MIN),
+        Big::new(5.0, i64::MAX)
+    );
+    assert!(Big::new(1.0, i64::MIN) < Big::new(1.0, i64::MAX));
+    assert_eq!(b(5) % Big::new(1.0, i64::MAX), b(5));
+    assert_eq!(Big::new(1.0, i64::MAX) % Big::new(1.0, i64::MIN), Big::Zero);
+}
+
+#[test]
+fn variance_and_std_dev() {
+    let values = [2, 4, 4, 4, 5, 5, 7, 9].map(b);
+    assert_eq!(variance(&values), Some(b(4)));
+    let std = std_dev(&values).unwrap();
+    assert!((std - b(2)).abs() < Big::new(1.0, -12));
+
+    // values far beyond f64
+    let values = [1, 3].map(|m| Big::new(m as f64, 400));
+    assert_eq!(variance(&values), Some(Big::new(1.0, 800)));
+    let std = std_dev(&values).unwrap();
+    assert!((std - Big::new(1.0, 400)).abs() < Big::new(1.0, 388));
+
+    assert_eq!(variance(&[b(42)]), Some(Big::Zero));
+    assert_eq!(variance(&[]), None);
+    assert_eq!(std_dev(&[]), None);
+    assert!(variance(&[b(1), Big::NaN]).unwrap().is_nan());
+    assert!(std_dev(&[b(1), Big::NaN]).unwrap().is_nan());
+}
+
+#[test]
+fn weighted_mean_of_pairs() {
+    // (2 * 1 + 4 * 2 + 10 * 5) / (1 + 2 + 5) = 60 / 8
+    let pairs = [(b(2), b(1)), (b(4), b(2)), (b(10), b(5))];
+    assert_eq!(weighted_mean(&pairs), Some(b(7.5)));
+
+    let huge = [(Big::new(1.0, 1000), b(1)), (Big::new(3.0, 1000), b(1))];
+    assert_eq!(weighted_mean(&huge), Some(Big::new(2.0, 1000)));
+
+    let many: Vec<(Big, Big)> = (1..=200).map(|i| (b(i), b(1))).collect();
+    assert_eq!(weighted_mean(&many), Some(b(100.5)));
+
+    assert_eq!(weighted_mean(&[]), None);
+    assert_eq!(weighted_mean(&[(b(5), Big::Zero)]), None);
+    assert_eq!(weighted_mean(&[(b(5), b(1)), (b(7), b(-1))]), None);
+    assert!(weighted_mean(&[(Big::NaN, b(1))]).unwrap().is_nan());
+}
+
+#[test]
+fn sum_pairwise_of_slice() {
+    assert_eq!(sum_pairwise(&[]), Big::Zero);
+    assert_eq!(sum_pairwise(&[b(5)]), b(5));
+    assert_eq!(sum_pairwise(&[b(1), b(2), b(3)]), b(6));
+    assert_eq!(
+        sum_pairwise(&[Big::new(1.0, 1000), b(-1)]),
+        Big::new(1.0, 1000)
+    );
+    assert!(sum_pairwise(&[b(1), Big::NaN, b(2)]).is_nan());
+
+    // 0.1 is not exact, but multiplying it by a power of 2 is
+    let values = vec![b(0.1); 1 << 16];
+    let exact = b(0.1) * b(1 << 16);
+    let linear = values.iter().fold(Big::Zero, |acc, value| acc + *value);
+    let pairwise = sum_pairwise(&values);
+    let linear_error = (linear - exact).abs();
+    let pairwise_error = (pairwise - exact).abs();
+    assert!(linear_error > Big::new(1.0, -9));
+    assert!(pairwise_error < linear_error / b(1000));
+}
+
+#[test]
+fn scale_all_values() {
+    let mut values = [b(3), b(-7.5), Big::new(6.0, 1000), Big::Zero];
+    scale_all(&mut values, 2.0);
+    assert_eq!(values, [b(6), b(-15), Big::new(1.2, 1001), Big::Zero]);
+
+    let mut values = [b(5), Big::new(1.0, -10)];
+    scale_all(&mut values, 0.5);
+    assert_eq!(values, [b(2.5), Big::new(5.0, -11)]);
+
+    // the mantissa overflows an f64, but the value does not
+    let mut values = [Big::new(9.0, 5)];
+    scale_all(&mut values, f64::MAX);
+    assert_eq!(values, [Big::new(9.0, 5) * b(f64::MAX)]);
+
+    let mut values = [Big::new(9.0, i64::MAX), Big::NaN, POS_INFINITY, b(4)];
+    scale_all(&mut values, -2.0);
+    assert!(values[0].is_neg_inf());
+    assert!(values[1].is_nan());
+    assert!(values[2].is_neg_inf());
+    assert_eq!(values[3], b(-8));
+
+    let mut values = [b(4), POS_INFINITY];
+    scale_all(&mut values, 0.0);
+    assert_eq!(values[0], Big::Zero);
+    assert!(values[1].is_nan());
+}
+
+#[test]
+fn extremes_of_iter() {
+    let values = vec![b(3), Big::NaN, Big::new(-1.0, 100), b(7), Big::NaN, b(5)];
+    assert_eq!(max_by_iter(values.clone()), Some(b(7)));
+    assert_eq!(min_by_iter(values), Some(Big::new(-1.0, 100)));
+
+    // any iterator works, not just slices
+    assert_eq!(max_by_iter((1..=10).map(b)), Some(b(10)));
+    assert_eq!(
+        min_by_iter((1..=10).map(|i| b(i * i - 10 * i))),
+        Some(b(-25))
+    );
+
+    assert!(max_by_iter([Big::Zero, POS_INFINITY, b(1)]).is_some_and(|max| max.is_pos_inf()));
+    assert!(min_by_iter([Big::Zero, NEG_INFINITY, b(1)]).is_some_and(|min| min.is_neg_inf()));
+    assert_eq!(max_by_iter(Vec::new()), None);
+    assert_eq!(min_by_iter([Big::NaN, Big::NaN]), None);
+}
+
+#[test]
+fn median_of_slice() {
+    assert_eq!(median(&mut [b(7), b(-3), b(5)]), Some(b(5)));
+    assert_eq!(median(&mut [b(7), b(-3), b(5), b(1)]), Some(b(3)));
+    assert_eq!(median(&mut [b(42)]), Some(b(42)));
+    assert_eq!(median(&mut []), None);
+
+    let mut values = [POS_INFINITY, b(1), NEG_INFINITY, POS_INFINITY, b(2)];
+    assert_eq!(median(&mut values), Some(b(2)));
+    assert!(values[0].is_neg_inf());
+    assert!(values[4].is_pos_inf());
+    assert!(median(&mut [POS_INFINITY, b(1), POS_INFINITY, b(2)])
+        .unwrap()
+        .is_pos_inf());
+    assert!(median(&mut [POS_INFINITY, NEG_INFINITY]).unwrap().is_nan());
+
+    assert!(median(&mut [b(1), Big::NaN, b(2)]).unwrap().is_nan());
+}
+
+#[test]
+fn min_positive() {
+    let min = Big::MIN_POSITIVE;
+    assert!(min > Big::Zero);
+    assert!(min.is_normalized());
+    assert_eq!(min.e(), i64::MIN);
+
+    let tiny = Big::new(5.0, i64::MIN + 1);
+    assert_eq!(tiny.e(), i64::MIN + 1);
+    assert_eq!(tiny / b(50), min);
+    assert_eq!(tiny / b(2), Big::new(2.5, i64::MIN + 1));
+    assert_eq!(tiny * b(0.5), Big::new(2.5, i64::MIN + 1));
+    assert_eq!(tiny + tiny, Big::new(1.0, i64::MIN + 2));
+    assert_eq!(tiny - b(0), tiny);
+    assert_eq!(
+        tiny % Big::new(3.0, i64::MIN + 1),
+        Big::new(2.0, i64::MIN + 1)
+    );
+    // the mantissa of the product brings the exponent back into range
+    assert_eq!(
+        Big::new(5.0, i64::MIN) * Big::new(5.0, -1),
+        Big::new(2.5, i64::MIN)
+    );
+    assert_eq!(
+        Big::new(2.0, i64::MAX) / Big::new(5.0, -1),
+        Big::new(4.0, i64::MAX)
+    );
+    assert!((Big::new(5.0, i64::MAX) / Big::new(5.0, -1)).is_pos_inf());
+
+    // below MIN_POSITIVE
+    assert_eq!(tiny / b(100), Big::Zero);
+    assert_eq!(min * b(0.5), Big::Zero);
+
+    assert!(tiny > min);
+    assert!(-tiny < Big::Zero);
+    assert_eq!(tiny.log10(), 5f64.log10() + (i64::MIN + 1) as f64);
+    assert_eq!(tiny.to_exponential(2), format!("5.00e{}", i64::MIN + 1));
+    assert_eq!(tiny.to_fixed(2), "0.00");
+    assert_eq!(tiny.powf(1.0), tiny);
+    assert_eq!(tiny.powf(2.0), Big::Zero);
+}
+
 #[test]
 fn parsing() {
     assert_eq!("1e1".parse(), Ok(b(10)));
@@ -196,6 +1940,96 @@ fn parsing() {
     assert!("nan".parse::<Big>().unwrap().is_nan());
 }
 
+#[test]
+fn parse_exponent_sign() {
+    assert_eq!("1.5E+10".parse(), Ok(Big::new(1.5, 10)));
+    assert_eq!("1.5e-10".parse(), Ok(Big::new(1.5, -10)));
+    assert_eq!("1.5E+400".parse(), Ok(Big::new(1.5, 400)));
+    assert_eq!("1.5e+400".parse(), Ok(Big::new(1.5, 400)));
+    assert_eq!("-2E-500".parse(), Ok(Big::new(-2.0, -500)));
+    assert_eq!("25e-400".parse(), Ok(Big::new(2.5, -399)));
+    assert_eq!("0e5".parse(), Ok(Big::Zero));
+    assert_eq!(
+        Big::new(1.5, 400).to_string().parse(),
+        Ok(Big::new(1.5, 400))
+    );
+    assert_eq!(
+        "1.5E+x".parse::<Big>(),
+        Err(ParseError::Exponent("+x".to_string()))
+    );
+}
+
+#[test]
+fn from_json_number_str() {
+    let number = Big::from_json_number_str("123456789012345678901234567890").unwrap();
+    assert_eq!(number.m(), 1.2345678901234567);
+    assert_eq!(number.e(), 29);
+    let number = Big::from_json_number_str("-999999999999999999999999999999").unwrap();
+    assert_eq!(number, Big::new(-1.0, 30));
+
+    assert_eq!(Big::from_json_number_str("42"), Ok(b(42)));
+    assert_eq!(Big::from_json_number_str("-0.00125"), Ok(b(-0.00125)));
+    assert_eq!(Big::from_json_number_str("1.5e400"), Ok(Big::new(1.5, 400)));
+    assert_eq!(
+        Big::from_json_number_str("12.5E-401"),
+        Ok(Big::new(1.25, -400))
+    );
+    assert_eq!(Big::from_json_number_str("0.0e5"), Ok(Big::Zero));
+    assert_eq!(Big::from_json_number_str("-0"), Ok(Big::Zero));
+    assert!(Big::from_json_number_str("1e9223372036854775807")
+        .unwrap()
+        .is_normalized());
+    assert!(Big::from_json_number_str("10e9223372036854775807")
+        .unwrap()
+        .is_pos_inf());
+
+    assert_eq!(
+        Big::from_json_number_str("1.2.3"),
+        Err(ParseError::Mantissa("1.2.3".to_string()))
+    );
+    assert_eq!(
+        Big::from_json_number_str("1."),
+        Err(ParseError::Mantissa("1.".to_string()))
+    );
+    assert_eq!(
+        Big::from_json_number_str("NaN"),
+        Err(ParseError::Mantissa("NaN".to_string()))
+    );
+    assert_eq!(
+        Big::from_json_number_str("1e"),
+        Err(ParseError::Exponent("".to_string()))
+    );
+}
+
+#[test]
+fn parse_human() {
+    assert_eq!(Big::parse_human("1.5 million"), Ok(b(1_500_000)));
+    assert_eq!(Big::parse_human("  -2 Billion "), Ok(b(-2e9)));
+    assert_eq!(Big::parse_human("1,500 thousand"), Ok(b(1_500_000)));
+    assert_eq!(Big::parse_human("3 vigintillion"), Ok(Big::new(3.0, 63)));
+
+    assert_eq!(Big::parse_human("1.5K"), Ok(b(1500)));
+    assert_eq!(Big::parse_human("1.5k"), Ok(b(1500)));
+    assert_eq!(Big::parse_human("2 M"), Ok(b(2_000_000)));
+    assert_eq!(Big::parse_human("4Qa"), Ok(Big::new(4.0, 15)));
+    assert_eq!(Big::parse_human("4qi"), Ok(Big::new(4.0, 18)));
+    assert_eq!(Big::parse_human("7Dc"), Ok(Big::new(7.0, 33)));
+
+    assert_eq!(Big::parse_human("2,000"), Ok(b(2000)));
+    assert_eq!(Big::parse_human("-1,234,567.5"), Ok(b(-1234567.5)));
+
+    assert_eq!(Big::parse_human("3e4"), Ok(b(30000)));
+    assert_eq!(Big::parse_human("1234.5"), Ok(b(1234.5)));
+    assert!(Big::parse_human("nan").unwrap().is_nan());
+    assert!(Big::parse_human("-inf").unwrap().is_neg_inf());
+
+    assert!(Big::parse_human("1,23").is_err());
+    assert!(Big::parse_human("12,345,67").is_err());
+    assert!(Big::parse_human("1.5 gazillion").is_err());
+    assert!(Big::parse_human("K").is_err());
+    assert!(Big::parse_human("").is_err());
+}
+
 #[test]
 #[should_panic]
 fn parse_invalid() {
@@ -203,3 +2037,26 @@ fn parse_invalid() {
     "adgkjsfe4".parse::<Big>().unwrap();
     "3eysdkfjsdf".parse::<Big>().unwrap();
 }
+
+#[test]
+fn atomic_from_threads() {
+    let total = BigAtomic::new(Big::new(1.0, 300));
+    std::thread::scope(|s| {
+        for thread in 0..8 {
+            let total = &total;
+            s.spawn(move || {
+                for _ in 0..1000 {
+                    total.fetch_add(&Big::new(1.0 + thread as f64, 297));
+                }
+            });
+        }
+    });
+    // 1e300 + 1000 * (1 + 2 + .. + 8) * 1e297
+    let expected = Big::new(3.7, 301);
+    assert!(((total.load() - expected) / expected).abs() < Big::new(1.0, -12));
+
+    let atomic = BigAtomic::from(b(5));
+    assert_eq!(atomic.fetch_add(&b(2)), b(5));
+    atomic.store(atomic.load() * b(2));
+    assert_eq!(atomic.into_inner(), b(14));
+}